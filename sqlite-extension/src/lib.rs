@@ -0,0 +1,248 @@
+//! A [SQLite loadable extension](https://www.sqlite.org/loadext.html) providing
+//! the UUID functions of `rosetta_uuid::sqlite`, for the tools that open the
+//! databases written by Diesel without going through the Rust API, such as the
+//! `sqlite3` command-line shell or DBeaver.
+//!
+//! The extension is built with:
+//!
+//! ```sh
+//! cargo build --release --manifest-path sqlite-extension/Cargo.toml
+//! ```
+//!
+//! and loaded in the `sqlite3` shell with:
+//!
+//! ```sql
+//! .load sqlite-extension/target/release/librosetta_uuid_sqlite
+//! SELECT uuid_to_text(id) FROM samples;
+//! ```
+//!
+//! It registers the same functions as `rosetta_uuid::sqlite::register_functions`,
+//! with the same behavior: `uuid_v4()`, `uuid_v7()`, `uuid_v5(namespace, name)`,
+//! `uuid_to_text(uuid)`, `uuid_from_text(text)`, `uuid_version(uuid)` and
+//! `uuid_timestamp(uuid)`, all implemented by `rosetta_uuid::sql_functions`.
+//! The UUIDs are read and written as 16-byte BLOBs.
+
+use core::ffi::{c_char, c_int};
+use std::ffi::CString;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use libsqlite3_sys as ffi;
+use rosetta_uuid::{Uuid, sql_functions};
+
+/// The value returned by a SQL function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Output {
+    /// A `NULL` value.
+    Null,
+    /// A UUID, returned as a 16-byte BLOB.
+    Uuid(Uuid),
+    /// A TEXT value.
+    Text(String),
+    /// An INTEGER value.
+    Integer(i64),
+}
+
+impl From<Option<Uuid>> for Output {
+    fn from(uuid: Option<Uuid>) -> Self {
+        uuid.map_or(Self::Null, Self::Uuid)
+    }
+}
+
+/// Signature of the functions registered by the extension, which receive the
+/// arguments of the call and return either its result or an error message.
+type Function = fn(&[*mut ffi::sqlite3_value]) -> Result<Output, String>;
+
+/// The functions registered by the extension: their name, number of
+/// arguments, whether they are deterministic and their implementation, shared
+/// with `rosetta_uuid::sqlite::register_functions`.
+static FUNCTIONS: [(&str, c_int, bool, Function); 7] = [
+    ("uuid_v4", 0, false, |_| {
+        Ok(Output::Uuid(sql_functions::uuid_v4()))
+    }),
+    ("uuid_v7", 0, false, |_| {
+        Ok(Output::Uuid(sql_functions::uuid_v7()))
+    }),
+    ("uuid_v5", 2, true, |arguments| {
+        let namespace = uuid_argument(arguments[0])?;
+        let name = text_argument(arguments[1]);
+        Ok(sql_functions::uuid_v5(namespace, name.as_deref()).into())
+    }),
+    ("uuid_to_text", 1, true, |arguments| {
+        Ok(sql_functions::uuid_to_text(uuid_argument(arguments[0])?)
+            .map_or(Output::Null, Output::Text))
+    }),
+    ("uuid_from_text", 1, true, |arguments| {
+        Ok(sql_functions::uuid_from_text(text_argument(arguments[0]).as_deref()).into())
+    }),
+    ("uuid_version", 1, true, |arguments| {
+        Ok(sql_functions::uuid_version(uuid_argument(arguments[0])?)
+            .map_or(Output::Null, |version| Output::Integer(i64::from(version))))
+    }),
+    ("uuid_timestamp", 1, true, |arguments| {
+        Ok(sql_functions::uuid_timestamp(uuid_argument(arguments[0])?)
+            .map_or(Output::Null, Output::Integer))
+    }),
+];
+
+/// Reads a UUID argument, stored as a 16-byte BLOB.
+///
+/// # Errors
+///
+/// * If the argument is not `NULL` and is not 16 bytes long.
+fn uuid_argument(value: *mut ffi::sqlite3_value) -> Result<Option<Uuid>, String> {
+    // SAFETY: `value` is one of the arguments SQLite passed to the function.
+    unsafe {
+        if ffi::sqlite3_value_type(value) == ffi::SQLITE_NULL {
+            return Ok(None);
+        }
+        let bytes = ffi::sqlite3_value_blob(value).cast::<u8>();
+        let length = usize::try_from(ffi::sqlite3_value_bytes(value)).unwrap_or_default();
+        let bytes = if bytes.is_null() {
+            &[]
+        } else {
+            core::slice::from_raw_parts(bytes, length)
+        };
+        Uuid::from_slice(bytes)
+            .map(Some)
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// Reads a TEXT argument.
+fn text_argument(value: *mut ffi::sqlite3_value) -> Option<String> {
+    // SAFETY: `value` is one of the arguments SQLite passed to the function.
+    unsafe {
+        if ffi::sqlite3_value_type(value) == ffi::SQLITE_NULL {
+            return None;
+        }
+        let text = ffi::sqlite3_value_text(value);
+        let length = usize::try_from(ffi::sqlite3_value_bytes(value)).unwrap_or_default();
+        if text.is_null() {
+            return Some(String::new());
+        }
+        Some(String::from_utf8_lossy(core::slice::from_raw_parts(text, length)).into_owned())
+    }
+}
+
+/// The callback registered for every function, dispatching to the
+/// implementation provided as user data and reporting its errors and panics
+/// to SQLite.
+unsafe extern "C" fn call(
+    context: *mut ffi::sqlite3_context,
+    argument_count: c_int,
+    values: *mut *mut ffi::sqlite3_value,
+) {
+    // SAFETY: the user data is the `Function` provided on registration, and
+    // SQLite passes `argument_count` arguments in `values`.
+    let (function, arguments) = unsafe {
+        let function = *ffi::sqlite3_user_data(context).cast::<Function>();
+        let arguments = if values.is_null() {
+            &[][..]
+        } else {
+            core::slice::from_raw_parts(values, usize::try_from(argument_count).unwrap_or_default())
+        };
+        (function, arguments)
+    };
+    let output = catch_unwind(AssertUnwindSafe(|| function(arguments)))
+        .unwrap_or_else(|_| Err("panic in a UUID function".to_owned()));
+
+    // SAFETY: `context` is the context SQLite passed to the function, and the
+    // buffers are copied by SQLite with `SQLITE_TRANSIENT`.
+    unsafe {
+        match output {
+            Ok(Output::Null) => ffi::sqlite3_result_null(context),
+            Ok(Output::Uuid(uuid)) => ffi::sqlite3_result_blob(
+                context,
+                uuid.as_bytes().as_ptr().cast(),
+                16,
+                ffi::SQLITE_TRANSIENT(),
+            ),
+            Ok(Output::Text(text)) => ffi::sqlite3_result_text(
+                context,
+                text.as_ptr().cast::<c_char>(),
+                c_int::try_from(text.len()).unwrap_or(c_int::MAX),
+                ffi::SQLITE_TRANSIENT(),
+            ),
+            Ok(Output::Integer(integer)) => ffi::sqlite3_result_int64(context, integer),
+            Err(message) => ffi::sqlite3_result_error(
+                context,
+                message.as_ptr().cast::<c_char>(),
+                c_int::try_from(message.len()).unwrap_or(c_int::MAX),
+            ),
+        }
+    }
+}
+
+/// Registers the UUID functions on the provided database connection.
+///
+/// # Safety
+///
+/// * The SQLite API must have been initialized with
+///   [`rusqlite_extension_init2`](ffi::rusqlite_extension_init2), and `db` must
+///   be a valid connection.
+unsafe fn register_functions(db: *mut ffi::sqlite3) -> c_int {
+    for (name, arity, deterministic, function) in &FUNCTIONS {
+        let name = CString::new(*name).expect("function names have no NUL byte");
+        // The functions have no side effects, so they are innocuous and can be
+        // used in the schema, such as in `DEFAULT` clauses and triggers, even
+        // when the schema is not trusted, as in the `sqlite3` shell.
+        let mut flags = ffi::SQLITE_UTF8 | ffi::SQLITE_INNOCUOUS;
+        if *deterministic {
+            flags |= ffi::SQLITE_DETERMINISTIC;
+        }
+        // SAFETY: the user data points into `FUNCTIONS`, which outlives the
+        // connection, and is only read by `call`.
+        let code = unsafe {
+            ffi::sqlite3_create_function_v2(
+                db,
+                name.as_ptr(),
+                *arity,
+                flags,
+                core::ptr::from_ref(function).cast_mut().cast(),
+                Some(call),
+                None,
+                None,
+                None,
+            )
+        };
+        if code != ffi::SQLITE_OK {
+            return code;
+        }
+    }
+    ffi::SQLITE_OK
+}
+
+/// The entry point called by SQLite when loading the extension.
+///
+/// # Safety
+///
+/// * Must only be called by SQLite, with a valid connection and API routines.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sqlite3_extension_init(
+    db: *mut ffi::sqlite3,
+    error_message: *mut *mut c_char,
+    api: *mut ffi::sqlite3_api_routines,
+) -> c_int {
+    // SAFETY: SQLite provides its API routines and the connection loading the
+    // extension.
+    unsafe {
+        if let Err(error) = ffi::rusqlite_extension_init2(api) {
+            if !error_message.is_null() {
+                // The message must be allocated by SQLite, which frees it, and
+                // its allocator is only missing if the API routines are.
+                let message = CString::new(error.to_string()).unwrap_or_default();
+                let message = message.as_bytes_with_nul();
+                *error_message = catch_unwind(|| {
+                    let buffer = ffi::sqlite3_malloc64(message.len() as u64).cast::<c_char>();
+                    if !buffer.is_null() {
+                        buffer.copy_from_nonoverlapping(message.as_ptr().cast(), message.len());
+                    }
+                    buffer
+                })
+                .unwrap_or(core::ptr::null_mut());
+            }
+            return ffi::SQLITE_ERROR;
+        }
+        register_functions(db)
+    }
+}
@@ -0,0 +1,147 @@
+//! Tests loading the built extension in the `sqlite3` shell, which must be
+//! installed, and comparing its results with `rosetta_uuid::sql_functions`.
+//!
+//! The extension cannot be loaded in a connection of this process: its
+//! `libsqlite3-sys` is built as a loadable extension, and would be unified
+//! with the one of any SQLite driver in the tests.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use rosetta_uuid::{Namespace, Uuid, sql_functions};
+
+/// Returns the path of the extension, which Cargo builds in the `deps`
+/// directory holding this test.
+fn extension() -> PathBuf {
+    let test = std::env::current_exe().unwrap();
+    test.parent().unwrap().join(format!(
+        "{}rosetta_uuid_sqlite{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    ))
+}
+
+/// Runs `sql` in the `sqlite3` shell after loading the extension, returning
+/// its output or its error message.
+fn query(sql: &str) -> Result<String, String> {
+    let output = Command::new(std::env::var("SQLITE3").unwrap_or_else(|_| "sqlite3".to_owned()))
+        .arg("-batch")
+        .arg(":memory:")
+        .arg(format!(".load '{}'", extension().display()))
+        .arg(sql)
+        .output()
+        .expect("the sqlite3 shell should be installed");
+    if output.status.success() {
+        Ok(String::from_utf8(output.stdout)
+            .unwrap()
+            .trim_end()
+            .to_owned())
+    } else {
+        Err(String::from_utf8(output.stderr).unwrap())
+    }
+}
+
+/// Returns the SQL BLOB literal of `uuid`.
+fn blob(uuid: Uuid) -> String {
+    format!("X'{}'", uuid.simple())
+}
+
+/// Parses the output of `hex(uuid)` in the shell.
+fn unhex(output: &str) -> Uuid {
+    output.parse().unwrap()
+}
+
+#[test]
+fn test_generation() {
+    let v4 = unhex(&query("SELECT hex(uuid_v4())").unwrap());
+    assert_eq!(v4.get_version_num(), 4);
+
+    let output = query("SELECT hex(uuid_v7()) UNION ALL SELECT hex(uuid_v7())").unwrap();
+    let (first, second) = output.split_once('\n').unwrap();
+    let (first, second) = (unhex(first), unhex(second));
+    assert_eq!(first.get_version_num(), 7);
+    assert!(first < second);
+}
+
+#[test]
+fn test_uuid_v5() {
+    let dns = Namespace::DNS.into_uuid();
+    assert_eq!(
+        unhex(
+            &query(&format!(
+                "SELECT hex(uuid_v5({}, 'www.example.com'))",
+                blob(dns)
+            ))
+            .unwrap()
+        ),
+        sql_functions::uuid_v5(Some(dns), Some("www.example.com")).unwrap()
+    );
+    assert_eq!(
+        query("SELECT uuid_v5(NULL, 'www.example.com') IS NULL").unwrap(),
+        "1"
+    );
+    assert_eq!(
+        query(&format!("SELECT uuid_v5({}, NULL) IS NULL", blob(dns))).unwrap(),
+        "1"
+    );
+    assert!(query("SELECT uuid_v5(X'0102', 'www.example.com')").is_err());
+}
+
+#[test]
+fn test_text_round_trip() {
+    let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    assert_eq!(
+        query(&format!("SELECT uuid_to_text({})", blob(uuid))).unwrap(),
+        sql_functions::uuid_to_text(Some(uuid)).unwrap()
+    );
+    for text in [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "67E5504410B1426F9247BB680E5FE0C8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        assert_eq!(
+            Some(unhex(
+                &query(&format!("SELECT hex(uuid_from_text('{text}'))")).unwrap()
+            )),
+            sql_functions::uuid_from_text(Some(text))
+        );
+    }
+    assert_eq!(
+        query("SELECT uuid_from_text('not a uuid') IS NULL").unwrap(),
+        "1"
+    );
+    assert_eq!(query("SELECT uuid_from_text(NULL) IS NULL").unwrap(), "1");
+    assert_eq!(query("SELECT uuid_to_text(NULL) IS NULL").unwrap(), "1");
+    assert!(query("SELECT uuid_to_text(X'0102')").is_err());
+}
+
+#[test]
+fn test_version_and_timestamp() {
+    let created_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    let v1 = Uuid::from_u128(0xc232ab00_9414_11ec_b3c8_9f6bdeced846);
+    for uuid in [
+        Uuid::v7_from(created_at).unwrap(),
+        v1,
+        Uuid::new_v4(),
+        Uuid::nil(),
+    ] {
+        let output = query(&format!(
+            "SELECT uuid_version({uuid}), coalesce(uuid_timestamp({uuid}), 'NULL')",
+            uuid = blob(uuid)
+        ))
+        .unwrap();
+        let timestamp = sql_functions::uuid_timestamp(Some(uuid))
+            .map_or_else(|| "NULL".to_owned(), |timestamp| timestamp.to_string());
+        assert_eq!(
+            output,
+            format!(
+                "{}|{timestamp}",
+                sql_functions::uuid_version(Some(uuid)).unwrap()
+            )
+        );
+    }
+    assert_eq!(query("SELECT uuid_version(NULL) IS NULL").unwrap(), "1");
+    assert_eq!(query("SELECT uuid_timestamp(NULL) IS NULL").unwrap(), "1");
+}
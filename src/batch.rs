@@ -0,0 +1,193 @@
+#![cfg(feature = "std")]
+//! Batch generation of version 4 and 7 UUIDs.
+//!
+//! [`Uuid::new_v4`] asks the operating system for random bytes for every
+//! single UUID. The batch constructors instead seed a ChaCha20 CSPRNG once,
+//! from the operating system, and draw the random bits of the whole batch from
+//! it, which is much cheaper when creating millions of identifiers at once.
+
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+
+use crate::{Uuid, V7Generator};
+
+/// Returns a ChaCha20 CSPRNG seeded from the entropy of the operating system.
+///
+/// The seed is drawn through the `uuid` crate, which already knows how to
+/// reach the entropy source of every supported platform, `wasm32` included.
+/// The two version 4 UUIDs provide 244 random bits.
+pub(crate) fn seeded_rng() -> ChaCha20Rng {
+    let mut seed = [0; 32];
+    seed[..16].copy_from_slice(uuid::Uuid::new_v4().as_bytes());
+    seed[16..].copy_from_slice(uuid::Uuid::new_v4().as_bytes());
+    ChaCha20Rng::from_seed(seed)
+}
+
+/// Creates a version 4 `Uuid` from the next random bytes of the provided CSPRNG.
+fn next_v4(rng: &mut ChaCha20Rng) -> Uuid {
+    let mut random_bytes = [0; 16];
+    rng.fill_bytes(&mut random_bytes);
+    Uuid::v4_from_random_bytes(random_bytes)
+}
+
+impl Uuid {
+    /// Fills the slice with random version 4 `Uuid`s, drawn from a CSPRNG
+    /// seeded once for the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let mut uuids = vec![Uuid::default(); 10_000];
+    /// Uuid::fill_v4(&mut uuids);
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(uuid::Version::Random)));
+    /// ```
+    pub fn fill_v4(uuids: &mut [Uuid]) {
+        let mut rng = seeded_rng();
+        for uuid in uuids {
+            *uuid = next_v4(&mut rng);
+        }
+    }
+
+    /// Fills the slice with strictly increasing version 7 `Uuid`s, using the
+    /// process-wide [`V7Generator`].
+    ///
+    /// The system clock is read again whenever the counter of a millisecond is
+    /// exhausted, and the random bits are drawn from a CSPRNG seeded once for
+    /// the whole batch. The UUIDs are also greater than
+    /// any previously created by [`Uuid::monotonic_v7`] or by another batch.
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let before = Uuid::monotonic_v7();
+    /// let mut uuids = vec![Uuid::default(); 10_000];
+    /// Uuid::fill_v7(&mut uuids);
+    /// assert!(before < uuids[0]);
+    /// assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn fill_v7(uuids: &mut [Uuid]) {
+        V7Generator::global().fill(uuids);
+    }
+
+    /// Returns an endless iterator of random version 4 `Uuid`s, drawn from a
+    /// CSPRNG seeded once when the iterator is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::collections::HashSet;
+    ///
+    /// let uuids: HashSet<Uuid> = Uuid::v4_iter().take(10_000).collect();
+    /// assert_eq!(uuids.len(), 10_000);
+    /// ```
+    pub fn v4_iter() -> impl Iterator<Item = Uuid> {
+        let mut rng = seeded_rng();
+        core::iter::repeat_with(move || next_v4(&mut rng))
+    }
+
+    /// Returns an endless iterator of strictly increasing version 7 `Uuid`s,
+    /// using the process-wide [`V7Generator`] as [`Uuid::fill_v7`] does.
+    ///
+    /// The system clock is read whenever a `Uuid` is pulled, see
+    /// [`V7Generator::iter`].
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time before the Unix epoch while iterating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuids: Vec<Uuid> = Uuid::v7_iter().take(10_000).collect();
+    /// assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn v7_iter() -> impl Iterator<Item = Uuid> {
+        V7Generator::global().iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedClock;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_fill_v4() {
+        let mut uuids = vec![Uuid::default(); 10_000];
+        Uuid::fill_v4(&mut uuids);
+        assert!(uuids.iter().all(|uuid| {
+            uuid.get_version() == Some(uuid::Version::Random)
+                && uuid.get_variant() == uuid::Variant::RFC4122
+        }));
+        assert_eq!(uuids.iter().collect::<HashSet<_>>().len(), uuids.len());
+
+        // Every batch is seeded independently.
+        let mut other = vec![Uuid::default(); 10_000];
+        Uuid::fill_v4(&mut other);
+        assert_eq!(
+            uuids.iter().chain(&other).collect::<HashSet<_>>().len(),
+            20_000
+        );
+    }
+
+    #[test]
+    fn test_fill_v7_shares_clock_readings() {
+        let readings = core::cell::Cell::new(0);
+        let clock = || {
+            readings.set(readings.get() + 1);
+            1_700_000_000_000_000_000
+        };
+        let generator = V7Generator::with_clock(clock);
+        let mut uuids = vec![Uuid::default(); 3 * 4096 + 1];
+        generator.fill(&mut uuids);
+        assert_eq!(readings.get(), 4);
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(
+            uuids
+                .iter()
+                .all(|uuid| uuid.get_version() == Some(uuid::Version::SortRand))
+        );
+
+        // Once the counter of a millisecond is exhausted, the timestamp moves on.
+        let millis = |uuid: &Uuid| uuid.get_timestamp().unwrap().to_unix();
+        assert_eq!(millis(&uuids[0]), (1_700_000_000, 0));
+        assert_eq!(millis(&uuids[4095]), (1_700_000_000, 0));
+        assert_eq!(millis(&uuids[4096]), (1_700_000_000, 1_000_000));
+    }
+
+    #[test]
+    fn test_fill_v7_before_epoch() {
+        let generator = V7Generator::with_clock(FixedClock::new(-1));
+        assert!(generator.try_fill(&mut [Uuid::default(); 3]).is_err());
+        // An empty batch does not read the clock.
+        assert_eq!(generator.try_fill(&mut []), Ok(()));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let v4: Vec<Uuid> = Uuid::v4_iter().take(1_000).collect();
+        assert!(
+            v4.iter()
+                .all(|uuid| uuid.get_version() == Some(uuid::Version::Random))
+        );
+        assert_eq!(v4.iter().collect::<HashSet<_>>().len(), v4.len());
+
+        let first = Uuid::monotonic_v7();
+        let v7: Vec<Uuid> = Uuid::v7_iter().take(10_000).collect();
+        assert!(first < v7[0]);
+        assert!(v7.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(v7[9_999] < Uuid::monotonic_v7());
+    }
+}
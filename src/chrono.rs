@@ -0,0 +1,231 @@
+#![cfg(feature = "chrono")]
+//! Submodule providing conversions between the timestamps embedded in
+//! time-based UUIDs and [`chrono`] types.
+
+use ::chrono::{DateTime, TimeDelta, TimeZone, Utc};
+
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{Clock, FixedClock, UnixTimestamp, Uuid};
+
+impl UnixTimestamp for DateTime<Utc> {
+    fn unix_timestamp_nanos(&self) -> i128 {
+        i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos())
+    }
+
+    fn from_unix_timestamp_nanos(unix_timestamp_nanos: i128) -> Option<Self> {
+        let seconds = i64::try_from(unix_timestamp_nanos.div_euclid(1_000_000_000)).ok()?;
+        let nanos = u32::try_from(unix_timestamp_nanos.rem_euclid(1_000_000_000)).ok()?;
+        DateTime::from_timestamp(seconds, nanos)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for FixedClock {
+    fn from(date_time: DateTime<Tz>) -> Self {
+        Self::new(date_time.to_utc().unix_timestamp_nanos())
+    }
+}
+
+impl Uuid {
+    #[must_use]
+    /// Returns the time embedded in a time-based (v1, v6 or v7) `Uuid`,
+    /// or `None` for the other versions.
+    ///
+    /// The precision is the one of the version: milliseconds for v7, and
+    /// 100 nanoseconds for v1 and v6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, Uuid};
+    ///
+    /// let created_at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    /// let uuid = Uuid::try_v7_with_clock(&FixedClock::from(created_at)).unwrap();
+    /// assert_eq!(uuid.created_at(), Some(created_at));
+    ///
+    /// assert_eq!(Uuid::new_v4().created_at(), None);
+    /// ```
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_as()
+    }
+
+    #[must_use]
+    /// Returns the time elapsed between the creation of a time-based `Uuid`
+    /// and the time reported by the provided [`Clock`], or `None` for the
+    /// versions which do not embed a timestamp.
+    ///
+    /// The age is negative when the `Uuid` was created after the time
+    /// reported by the clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, Uuid};
+    ///
+    /// let uuid = Uuid::try_v7_with_clock(&FixedClock::new(1_700_000_000_000_000_000)).unwrap();
+    /// let later = FixedClock::new(1_700_000_060_000_000_000);
+    /// assert_eq!(uuid.age(&later), Some(chrono::TimeDelta::seconds(60)));
+    /// ```
+    pub fn age<C: Clock + ?Sized>(&self, clock: &C) -> Option<TimeDelta> {
+        Some(
+            DateTime::from_unix_timestamp_nanos(clock.unix_timestamp_nanos())?
+                - self.created_at()?,
+        )
+    }
+
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Returns whether the `Uuid` was created more than `duration` ago,
+    /// according to the [`SystemClock`].
+    ///
+    /// Versions which do not embed a timestamp are never considered older
+    /// than any duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use rosetta_uuid::{FixedClock, Uuid};
+    ///
+    /// let uuid = Uuid::try_v7_with_clock(&FixedClock::new(1_700_000_000_000_000_000)).unwrap();
+    /// assert!(uuid.is_older_than(TimeDelta::days(1)));
+    /// assert!(!Uuid::utc_v7().is_older_than(TimeDelta::days(1)));
+    /// assert!(!Uuid::new_v4().is_older_than(TimeDelta::zero()));
+    /// ```
+    pub fn is_older_than(&self, duration: TimeDelta) -> bool {
+        self.age(&SystemClock).is_some_and(|age| age > duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_created_at_v7() {
+        let created_at = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+        let uuid = Uuid::try_v7_with_clock(&FixedClock::from(created_at)).unwrap();
+        // Version 7 UUIDs only have millisecond precision.
+        assert_eq!(
+            uuid.created_at(),
+            DateTime::from_timestamp(1_700_000_000, 123_000_000)
+        );
+    }
+
+    #[test]
+    fn test_created_at_v1_and_v6() {
+        let (ticks, counter) =
+            uuid::Timestamp::from_unix_time(1_700_000_000, 123_456_700, 0, 14).to_gregorian();
+        let node_id = [1, 2, 3, 4, 5, 6];
+        let expected = DateTime::from_timestamp(1_700_000_000, 123_456_700);
+
+        let v1 = Uuid::from(
+            uuid::Builder::from_gregorian_timestamp(ticks, counter, &node_id).into_uuid(),
+        );
+        assert_eq!(v1.get_version(), Some(uuid::Version::Mac));
+        assert_eq!(v1.created_at(), expected);
+
+        let v6 = Uuid::from(
+            uuid::Builder::from_sorted_gregorian_timestamp(ticks, counter, &node_id).into_uuid(),
+        );
+        assert_eq!(v6.get_version(), Some(uuid::Version::SortMac));
+        assert_eq!(v6.created_at(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_created_at_without_timestamp() {
+        assert_eq!(Uuid::new_v4().created_at(), None);
+        assert_eq!(Uuid::default().created_at(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_age() {
+        let uuid = Uuid::try_v7_with_clock(&FixedClock::new(1_700_000_000_000_000_000)).unwrap();
+        assert_eq!(
+            uuid.age(&FixedClock::new(1_700_000_001_500_000_000)),
+            Some(TimeDelta::milliseconds(1_500))
+        );
+        assert_eq!(
+            uuid.age(&FixedClock::new(1_699_999_999_000_000_000)),
+            Some(TimeDelta::seconds(-1))
+        );
+        assert_eq!(Uuid::new_v4().age(&SystemClock), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_is_older_than() {
+        let uuid = Uuid::try_v7_with_clock(&FixedClock::new(1_700_000_000_000_000_000)).unwrap();
+        assert!(uuid.is_older_than(TimeDelta::days(365)));
+
+        let fresh = Uuid::utc_v7();
+        assert!(!fresh.is_older_than(TimeDelta::hours(1)));
+        assert!(!Uuid::new_v4().is_older_than(TimeDelta::zero()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_bounds() {
+        let date_time = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+        let lower = Uuid::v7_lower_bound(date_time);
+        let upper = Uuid::v7_upper_bound(date_time);
+        assert_eq!(lower.get_version(), Some(uuid::Version::SortRand));
+        assert_eq!(upper.get_version(), Some(uuid::Version::SortRand));
+        assert_eq!(lower.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(upper.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(lower.created_at(), upper.created_at());
+
+        for _ in 0..100 {
+            let uuid = Uuid::try_v7_with_clock(&FixedClock::from(date_time)).unwrap();
+            assert!(lower <= uuid && uuid <= upper);
+        }
+
+        let next = date_time + TimeDelta::milliseconds(1);
+        assert!(upper < Uuid::v7_lower_bound(next));
+    }
+
+    #[test]
+    fn test_v7_bounds_are_clamped() {
+        let before_epoch = DateTime::from_timestamp(-1, 0).unwrap();
+        assert_eq!(
+            Uuid::v7_lower_bound(before_epoch),
+            Uuid::v7_lower_bound(DateTime::UNIX_EPOCH)
+        );
+        assert_eq!(
+            Uuid::v7_upper_bound(DateTime::<Utc>::MAX_UTC).as_bytes()[..6],
+            [0xFF; 6]
+        );
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        assert_eq!(
+            DateTime::from_unix_timestamp_nanos(-1),
+            DateTime::from_timestamp(-1, 999_999_999)
+        );
+        assert_eq!(DateTime::<Utc>::from_unix_timestamp_nanos(i128::MAX), None);
+        assert_eq!(
+            DateTime::from_timestamp(-1, 999_999_999)
+                .unwrap()
+                .unix_timestamp_nanos(),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_fixed_clock_from_date_time() {
+        let date_time = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+        assert_eq!(
+            FixedClock::from(date_time),
+            FixedClock::new(1_700_000_000_123_456_789)
+        );
+        let offset = ::chrono::FixedOffset::east_opt(3_600).unwrap();
+        assert_eq!(
+            FixedClock::from(date_time.with_timezone(&offset)),
+            FixedClock::from(date_time)
+        );
+    }
+}
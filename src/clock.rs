@@ -0,0 +1,234 @@
+//! Submodule defining the [`Clock`] trait, used as the source of time by every
+//! constructor of timestamp-based UUIDs.
+//!
+//! The [`SystemClock`] is used by default, and is available with the `std`
+//! feature. Tests and simulations can pin the time with a [`FixedClock`], or
+//! script it with any closure returning the number of nanoseconds since the
+//! Unix epoch, such as a reading of the real-time clock of a microcontroller.
+
+use crate::Error;
+
+/// A source of time for timestamp-based UUIDs.
+///
+/// # Examples
+///
+/// Any closure returning the nanoseconds since the Unix epoch is a clock, which
+/// makes it possible to script the time observed by the constructors:
+///
+/// ```
+/// use core::cell::Cell;
+/// use rosetta_uuid::Uuid;
+///
+/// let now = Cell::new(1_700_000_000_000_000_000_i128);
+/// let clock = || now.replace(now.get() + 1_000_000);
+///
+/// let first = Uuid::try_v7_with_clock(&clock).unwrap();
+/// let second = Uuid::try_v7_with_clock(&clock).unwrap();
+/// assert!(first < second);
+/// ```
+pub trait Clock {
+    /// Returns the current time, as the number of nanoseconds since the Unix epoch.
+    fn unix_timestamp_nanos(&self) -> i128;
+
+    /// Returns the current time, as the number of milliseconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// * If the current time is before the Unix epoch.
+    /// * If the current time is too far in the future for its milliseconds to
+    ///   fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Clock, Error, FixedClock};
+    ///
+    /// assert_eq!(FixedClock::new(1_500_000_000).unix_timestamp_millis(), Ok(1_500));
+    /// assert_eq!(
+    ///     FixedClock::new(-1).unix_timestamp_millis(),
+    ///     Err(Error::TimeBeforeUnixEpoch { unix_timestamp_nanos: -1 })
+    /// );
+    /// ```
+    fn unix_timestamp_millis(&self) -> Result<u64, Error> {
+        let unix_timestamp_nanos = self.unix_timestamp_nanos();
+        u64::try_from(unix_timestamp_nanos.div_euclid(1_000_000)).map_err(|_| {
+            if unix_timestamp_nanos < 0 {
+                Error::TimeBeforeUnixEpoch {
+                    unix_timestamp_nanos,
+                }
+            } else {
+                Error::TimestampOutOfRange {
+                    unix_timestamp_nanos,
+                }
+            }
+        })
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> i128,
+{
+    fn unix_timestamp_nanos(&self) -> i128 {
+        self()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// The clock of the operating system, read through
+/// [`SystemTime::now`](std::time::SystemTime::now), or through the JavaScript
+/// `Date` on `wasm32-unknown-unknown`, where the former is not available.
+///
+/// A clock set before the Unix epoch produces a negative reading, which the
+/// fallible constructors report as [`Error::TimeBeforeUnixEpoch`].
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Clock, SystemClock};
+///
+/// assert!(SystemClock.unix_timestamp_nanos() > 0);
+/// ```
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn unix_timestamp_nanos(&self) -> i128 {
+        use crate::UnixTimestamp;
+        std::time::SystemTime::now().unix_timestamp_nanos()
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    fn unix_timestamp_nanos(&self) -> i128 {
+        let (seconds, nanos) = uuid::Timestamp::now(uuid::NoContext).to_unix();
+        i128::from(seconds) * 1_000_000_000 + i128::from(nanos)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// A clock pinned to a fixed instant.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Clock, FixedClock};
+///
+/// let clock = FixedClock::new(1_700_000_000_000_000_000);
+/// assert_eq!(clock.unix_timestamp_nanos(), 1_700_000_000_000_000_000);
+/// assert_eq!(clock.unix_timestamp_nanos(), 1_700_000_000_000_000_000);
+/// ```
+pub struct FixedClock {
+    /// The pinned instant, in nanoseconds since the Unix epoch.
+    unix_timestamp_nanos: i128,
+}
+
+impl FixedClock {
+    #[must_use]
+    /// Creates a new clock pinned to the provided number of nanoseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::FixedClock;
+    ///
+    /// let clock = FixedClock::new(0);
+    /// ```
+    pub const fn new(unix_timestamp_nanos: i128) -> Self {
+        Self {
+            unix_timestamp_nanos,
+        }
+    }
+}
+
+impl Clock for FixedClock {
+    fn unix_timestamp_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_clock() {
+        let since_epoch = || {
+            std::time::SystemTime::UNIX_EPOCH
+                .elapsed()
+                .unwrap()
+                .as_millis()
+        };
+        let before = since_epoch();
+        let now = SystemClock.unix_timestamp_millis().unwrap();
+        let after = since_epoch();
+        assert!(u128::from(now) >= before);
+        assert!(u128::from(now) <= after);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_before_unix_epoch() {
+        use crate::{UnixTimestamp, Uuid};
+        use std::time::{Duration, SystemTime};
+
+        // The system clock is read as a `SystemTime`, whose conversion keeps
+        // the instants before the epoch instead of panicking.
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_millis(5);
+        let clock = || before_epoch.unix_timestamp_nanos();
+        assert_eq!(
+            Uuid::try_v7_with_clock(&clock),
+            Err(Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -5_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock::new(1_700_000_000_123_456_789);
+        assert_eq!(clock.unix_timestamp_nanos(), 1_700_000_000_123_456_789);
+        assert_eq!(clock.unix_timestamp_millis(), Ok(1_700_000_000_123));
+    }
+
+    #[test]
+    fn test_before_unix_epoch() {
+        let clock = FixedClock::new(-1_500_000);
+        assert_eq!(
+            clock.unix_timestamp_millis(),
+            Err(Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1_500_000
+            })
+        );
+        assert_eq!(FixedClock::new(999_999).unix_timestamp_millis(), Ok(0));
+    }
+
+    #[test]
+    fn test_after_u64_millis() {
+        let unix_timestamp_nanos = (i128::from(u64::MAX) + 1) * 1_000_000;
+        assert_eq!(
+            FixedClock::new(unix_timestamp_nanos).unix_timestamp_millis(),
+            Err(Error::TimestampOutOfRange {
+                unix_timestamp_nanos
+            })
+        );
+        assert_eq!(
+            FixedClock::new(unix_timestamp_nanos - 1).unix_timestamp_millis(),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_scripted_clock() {
+        let ticks = Cell::new(0_i128);
+        let clock = || {
+            ticks.set(ticks.get() + 1);
+            ticks.get() * 1_000_000
+        };
+        assert_eq!(clock.unix_timestamp_millis(), Ok(1));
+        assert_eq!(clock.unix_timestamp_millis(), Ok(2));
+    }
+}
@@ -27,3 +27,326 @@ mod sqlite;
 )]
 #[cfg_attr(feature = "sqlite", diesel(sqlite_type(name = "Binary")))]
 pub struct Uuid;
+
+#[cfg(feature = "sqlite")]
+/// A lenient variant of the [`Uuid`] SQL type for SQLite columns whose rows
+/// store their UUIDs in different representations, as SQLite does not enforce
+/// the type of a column.
+///
+/// Reading a value checks its storage class and accepts:
+///
+/// * a 16-byte BLOB, as written with the [`Uuid`] SQL type,
+/// * a TEXT, or a BLOB of text, in any representation accepted by
+///   [`crate::Uuid::parse`]: hyphenated, simple, braced or URN, in either case.
+///
+/// Writing a value always stores the canonical 16-byte BLOB, so that rewriting
+/// the rows of such a column normalizes it. Filters compare the stored values,
+/// so that `column.eq(uuid)` only matches the rows already normalized.
+///
+/// # Examples
+///
+/// ```
+/// use diesel::prelude::*;
+/// use rosetta_uuid::Uuid;
+///
+/// diesel::table! {
+///     legacy (rowid) {
+///         rowid -> Integer,
+///         id -> rosetta_uuid::diesel_impls::LenientUuid,
+///     }
+/// }
+///
+/// let mut connection = SqliteConnection::establish(":memory:").unwrap();
+/// diesel::sql_query("CREATE TABLE legacy (id)").execute(&mut connection).unwrap();
+/// diesel::sql_query(
+///     "INSERT INTO legacy (id) VALUES
+///         (X'67E5504410B1426F9247BB680E5FE0C8'),
+///         ('67e55044-10b1-426f-9247-bb680e5fe0c8'),
+///         ('67E5504410B1426F9247BB680E5FE0C8')",
+/// )
+/// .execute(&mut connection)
+/// .unwrap();
+///
+/// let ids: Vec<Uuid> = legacy::table.select(legacy::id).load(&mut connection).unwrap();
+/// assert!(ids.iter().all(|id| id.to_string() == "67e55044-10b1-426f-9247-bb680e5fe0c8"));
+///
+/// let id = Uuid::new_v4();
+/// diesel::insert_into(legacy::table)
+///     .values(legacy::id.eq(id))
+///     .execute(&mut connection)
+///     .unwrap();
+/// let found: Uuid = legacy::table
+///     .select(legacy::id)
+///     .filter(legacy::id.eq(id))
+///     .get_result(&mut connection)
+///     .unwrap();
+/// assert_eq!(found, id);
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, diesel::query_builder::QueryId, diesel::sql_types::SqlType,
+)]
+#[diesel(sqlite_type(name = "Binary"))]
+pub struct LenientUuid;
+
+/// Implements [`FromSql`](diesel::deserialize::FromSql) and
+/// [`ToSql`](diesel::serialize::ToSql) for a version-constrained wrapper, for
+/// the provided SQL types and every backend supported by [`crate::Uuid`].
+/// Decoded values of the wrong version or variant are rejected.
+macro_rules! impl_diesel_for_versioned_uuid {
+    ($name:ty, $($sql_type:ty),+) => {
+        $(
+            impl<DB> diesel::deserialize::FromSql<$sql_type, DB> for $name
+            where
+                DB: diesel::backend::Backend,
+                crate::Uuid: diesel::deserialize::FromSql<$sql_type, DB>,
+            {
+                fn from_sql(value: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+                    Ok(Self::try_from(<crate::Uuid as diesel::deserialize::FromSql<
+                        $sql_type,
+                        DB,
+                    >>::from_sql(value)?)?)
+                }
+            }
+
+            impl<DB> diesel::serialize::ToSql<$sql_type, DB> for $name
+            where
+                DB: diesel::backend::Backend,
+                crate::Uuid: diesel::serialize::ToSql<$sql_type, DB>,
+            {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut diesel::serialize::Output<'b, '_, DB>,
+                ) -> diesel::serialize::Result {
+                    <crate::Uuid as diesel::serialize::ToSql<$sql_type, DB>>::to_sql(
+                        self.as_uuid(),
+                        out,
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_diesel_for_versioned_uuid!(
+    crate::UuidV4,
+    Uuid,
+    diesel::sql_types::Binary,
+    diesel::sql_types::Text
+);
+impl_diesel_for_versioned_uuid!(
+    crate::UuidV7,
+    Uuid,
+    diesel::sql_types::Binary,
+    diesel::sql_types::Text
+);
+#[cfg(feature = "postgres")]
+impl_diesel_for_versioned_uuid!(crate::UuidV4, diesel::sql_types::Uuid);
+#[cfg(feature = "postgres")]
+impl_diesel_for_versioned_uuid!(crate::UuidV7, diesel::sql_types::Uuid);
+#[cfg(feature = "sqlite")]
+impl_diesel_for_versioned_uuid!(crate::UuidV4, LenientUuid);
+#[cfg(feature = "sqlite")]
+impl_diesel_for_versioned_uuid!(crate::UuidV7, LenientUuid);
+
+impl<P, ST, DB> diesel::deserialize::FromSql<ST, DB> for crate::PrefixedUuid<P>
+where
+    P: crate::Prefix,
+    DB: diesel::backend::Backend,
+    crate::Uuid: diesel::deserialize::FromSql<ST, DB>,
+{
+    fn from_sql(value: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        <crate::Uuid as diesel::deserialize::FromSql<ST, DB>>::from_sql(value).map(Self::new)
+    }
+}
+
+impl<P, DB> diesel::serialize::ToSql<Uuid, DB> for crate::PrefixedUuid<P>
+where
+    P: crate::Prefix,
+    DB: diesel::backend::Backend,
+    crate::Uuid: diesel::serialize::ToSql<Uuid, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <crate::Uuid as diesel::serialize::ToSql<Uuid, DB>>::to_sql(self.as_uuid(), out)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<P, DB> diesel::serialize::ToSql<diesel::sql_types::Uuid, DB> for crate::PrefixedUuid<P>
+where
+    P: crate::Prefix,
+    DB: diesel::backend::Backend,
+    crate::Uuid: diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <crate::Uuid as diesel::serialize::ToSql<diesel::sql_types::Uuid, DB>>::to_sql(
+            self.as_uuid(),
+            out,
+        )
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<P, DB> diesel::serialize::ToSql<LenientUuid, DB> for crate::PrefixedUuid<P>
+where
+    P: crate::Prefix,
+    DB: diesel::backend::Backend,
+    crate::Uuid: diesel::serialize::ToSql<LenientUuid, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        <crate::Uuid as diesel::serialize::ToSql<LenientUuid, DB>>::to_sql(self.as_uuid(), out)
+    }
+}
+
+/// Expression methods for columns storing a [`crate::Uuid`] as the [`Uuid`] SQL
+/// type or, on Postgres, as the native [`diesel::sql_types::Uuid`], see
+/// [`UuidSqlType`].
+///
+/// Both the Postgres `uuid` type and the SQLite BLOB compare UUIDs byte by
+/// byte, so that version 7 UUIDs sort by their creation time and a time range
+/// can be turned into a primary-key range scan. The bounds of the range can be
+/// of any [`UnixTimestamp`](crate::UnixTimestamp) type, such as a `chrono`,
+/// `time` or `jiff` instant.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "sqlite")]
+/// # fn main() {
+/// use diesel::prelude::*;
+/// use rosetta_uuid::diesel_impls::UuidExpressionMethods;
+/// use std::time::{Duration, SystemTime};
+///
+/// diesel::table! {
+///     samples (id) {
+///         id -> rosetta_uuid::diesel_impls::Uuid,
+///     }
+/// }
+///
+/// let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let end = start + Duration::from_secs(86_400);
+/// let query = samples::table.filter(samples::id.created_between(start, end));
+/// let sql = diesel::debug_query::<diesel::sqlite::Sqlite, _>(&query).to_string();
+/// assert!(sql.contains("BETWEEN"));
+/// # }
+/// # #[cfg(not(feature = "sqlite"))]
+/// # fn main() {}
+/// ```
+pub trait UuidExpressionMethods: diesel::Expression + Sized
+where
+    Self::SqlType: UuidSqlType,
+    crate::Uuid: diesel::expression::AsExpression<Self::SqlType>,
+{
+    /// Creates a SQL `BETWEEN` expression matching the version 7 UUIDs created
+    /// between the milliseconds of `start` and `end`, both inclusive.
+    fn created_between<T: crate::UnixTimestamp>(
+        self,
+        start: T,
+        end: T,
+    ) -> diesel::dsl::Between<Self, crate::Uuid, crate::Uuid> {
+        diesel::ExpressionMethods::between(
+            self,
+            crate::Uuid::v7_lower_bound(start),
+            crate::Uuid::v7_upper_bound(end),
+        )
+    }
+
+    /// Creates a SQL `>=` expression matching the version 7 UUIDs created
+    /// during or after the millisecond of `start`.
+    fn created_since<T: crate::UnixTimestamp>(
+        self,
+        start: T,
+    ) -> diesel::dsl::GtEq<Self, crate::Uuid> {
+        diesel::ExpressionMethods::ge(self, crate::Uuid::v7_lower_bound(start))
+    }
+
+    /// Creates a SQL `<=` expression matching the version 7 UUIDs created
+    /// during or before the millisecond of `end`.
+    fn created_until<T: crate::UnixTimestamp>(
+        self,
+        end: T,
+    ) -> diesel::dsl::LtEq<Self, crate::Uuid> {
+        diesel::ExpressionMethods::le(self, crate::Uuid::v7_upper_bound(end))
+    }
+
+    /// Creates a SQL expression matching any of the provided UUIDs.
+    ///
+    /// On Postgres, the UUIDs are bound as a single `uuid[]` parameter and
+    /// compared with `= ANY($1)`, so that the prepared statement is the same
+    /// for any number of UUIDs. The other backends use an `IN` list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// use diesel::prelude::*;
+    /// use rosetta_uuid::Uuid;
+    /// use rosetta_uuid::diesel_impls::UuidExpressionMethods;
+    ///
+    /// diesel::table! {
+    ///     samples (id) {
+    ///         id -> rosetta_uuid::diesel_impls::Uuid,
+    ///     }
+    /// }
+    ///
+    /// let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    /// let query = samples::table.filter(samples::id.eq_any_uuid(ids));
+    /// let sql = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
+    /// assert!(sql.contains("= ANY($1)"));
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    fn eq_any_uuid<I>(self, uuids: I) -> diesel::dsl::EqAny<Self, Vec<crate::Uuid>>
+    where
+        I: IntoIterator,
+        I::Item: Into<crate::Uuid>,
+    {
+        diesel::ExpressionMethods::eq_any(
+            self,
+            uuids.into_iter().map(Into::into).collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T> UuidExpressionMethods for T
+where
+    T: diesel::Expression,
+    T::SqlType: UuidSqlType,
+    crate::Uuid: diesel::expression::AsExpression<T::SqlType>,
+{
+}
+
+/// Module sealing [`UuidSqlType`].
+mod sealed {
+    /// Implemented by the SQL types of [`UuidSqlType`](super::UuidSqlType) only.
+    pub trait Sealed {}
+}
+
+/// The SQL types storing a [`crate::Uuid`] in its binary form, whose columns
+/// get the [`UuidExpressionMethods`]: the [`Uuid`] SQL type of this crate, and
+/// the native [`diesel::sql_types::Uuid`] of Postgres, as declared by
+/// `diesel print-schema`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait UuidSqlType:
+    sealed::Sealed + diesel::sql_types::SqlType + diesel::sql_types::SingleValue
+{
+}
+
+impl sealed::Sealed for Uuid {}
+impl UuidSqlType for Uuid {}
+
+#[cfg(feature = "postgres")]
+impl sealed::Sealed for diesel::sql_types::Uuid {}
+#[cfg(feature = "postgres")]
+impl UuidSqlType for diesel::sql_types::Uuid {}
@@ -3,7 +3,7 @@
 
 impl diesel::deserialize::FromSql<crate::diesel_impls::Uuid, diesel::pg::Pg> for crate::Uuid {
     fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
-        <uuid::Uuid as diesel::deserialize::FromSql<diesel::sql_types::Uuid, diesel::pg::Pg>>::from_sql(value).map(Self::from)
+        crate::Uuid::from_slice(value.as_bytes()).map_err(Into::into)
     }
 }
 
@@ -19,11 +19,27 @@ impl diesel::serialize::ToSql<crate::diesel_impls::Uuid, diesel::pg::Pg> for cra
     }
 }
 
+impl diesel::deserialize::FromSql<diesel::sql_types::Uuid, diesel::pg::Pg> for crate::Uuid {
+    fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        crate::Uuid::from_slice(value.as_bytes()).map_err(Into::into)
+    }
+}
+
+impl diesel::serialize::ToSql<diesel::sql_types::Uuid, diesel::pg::Pg> for crate::Uuid {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+    ) -> diesel::serialize::Result {
+        <uuid::Uuid as diesel::serialize::ToSql<diesel::sql_types::Uuid, diesel::pg::Pg>>::to_sql(
+            self.as_ref(),
+            out,
+        )
+    }
+}
+
 impl diesel::deserialize::FromSql<diesel::sql_types::Binary, diesel::pg::Pg> for crate::Uuid {
     fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
-        uuid::Uuid::from_slice(value.as_bytes())
-            .map_err(Into::into)
-            .map(Self::from)
+        crate::Uuid::from_slice(value.as_bytes()).map_err(Into::into)
     }
 }
 
@@ -41,11 +57,10 @@ impl diesel::serialize::ToSql<diesel::sql_types::Binary, diesel::pg::Pg> for cra
 
 impl diesel::deserialize::FromSql<diesel::sql_types::Text, diesel::pg::Pg> for crate::Uuid {
     fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
-        use std::str::FromStr;
-        let string = std::str::from_utf8(value.as_bytes())?;
-        uuid::Uuid::from_str(string)
+        core::str::from_utf8(value.as_bytes())
+            .map_err(crate::Error::from)
+            .and_then(str::parse)
             .map_err(Into::into)
-            .map(Self::from)
     }
 }
 
@@ -166,4 +181,298 @@ mod tests {
 
         assert_eq!(result.id, uuid);
     }
+
+    diesel::table! {
+        native_samples (id) {
+            id -> diesel::sql_types::Uuid,
+        }
+    }
+
+    #[test]
+    fn test_postgres_native_uuid_roundtrip() {
+        let Ok(database_url) = env::var("DATABASE_URL") else {
+            eprintln!("Skipping test_postgres_native_uuid_roundtrip: DATABASE_URL not set");
+            return;
+        };
+
+        let mut conn = PgConnection::establish(&database_url).unwrap();
+
+        diesel::sql_query("CREATE TEMPORARY TABLE native_samples (id UUID PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let uuid = Uuid::new_v4();
+        let uuid_v7 = crate::UuidV7::now();
+
+        diesel::insert_into(native_samples::table)
+            .values([
+                native_samples::id.eq(uuid),
+                native_samples::id.eq(Uuid::from(uuid_v7)),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+
+        let loaded = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.eq(uuid))
+            .get_result::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(loaded, uuid);
+
+        let loaded_v7 = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.eq(uuid_v7))
+            .get_result::<crate::UuidV7>(&mut conn)
+            .unwrap();
+        assert_eq!(loaded_v7, uuid_v7);
+
+        assert!(
+            native_samples::table
+                .select(native_samples::id)
+                .filter(native_samples::id.eq(uuid))
+                .get_result::<crate::UuidV7>(&mut conn)
+                .is_err()
+        );
+
+        // The values are interchangeable with the SQL type of this crate.
+        let result = diesel::sql_query("SELECT id FROM native_samples WHERE id = $1")
+            .bind::<diesel::sql_types::Uuid, _>(uuid)
+            .get_result::<Row>(&mut conn)
+            .unwrap();
+        assert_eq!(result.id, uuid);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct User;
+
+    impl crate::Prefix for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[test]
+    fn test_postgres_native_uuid_expression_methods() {
+        use crate::diesel_impls::UuidExpressionMethods;
+        use std::time::{Duration, SystemTime};
+
+        let Ok(database_url) = env::var("DATABASE_URL") else {
+            eprintln!(
+                "Skipping test_postgres_native_uuid_expression_methods: DATABASE_URL not set"
+            );
+            return;
+        };
+
+        let mut conn = PgConnection::establish(&database_url).unwrap();
+
+        diesel::sql_query("CREATE TEMPORARY TABLE native_samples (id UUID PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let at = |seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        let ids = [1_000, 2_000, 3_000].map(|seconds| Uuid::v7_from(at(seconds)).unwrap());
+        diesel::insert_into(native_samples::table)
+            .values(ids.map(|id| native_samples::id.eq(id)))
+            .execute(&mut conn)
+            .unwrap();
+
+        let between = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.created_between(at(1_500), at(2_500)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(between, vec![ids[1]]);
+
+        let since = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.created_since(at(2_000)))
+            .order(native_samples::id)
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(since, vec![ids[1], ids[2]]);
+
+        let until = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.created_until(at(1_999)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(until, vec![ids[0]]);
+
+        let mut found = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.eq_any_uuid([ids[2], ids[0], Uuid::new_v4()]))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        found.sort();
+        assert_eq!(found, vec![ids[0], ids[2]]);
+
+        // Prefixed identifiers are written to and read from native columns.
+        let prefixed = crate::PrefixedUuid::<User>::new(Uuid::utc_v7());
+        diesel::insert_into(native_samples::table)
+            .values(native_samples::id.eq(prefixed))
+            .execute(&mut conn)
+            .unwrap();
+        let loaded = native_samples::table
+            .select(native_samples::id)
+            .filter(native_samples::id.eq(prefixed))
+            .get_result::<crate::PrefixedUuid<User>>(&mut conn)
+            .unwrap();
+        assert_eq!(loaded, prefixed);
+    }
+
+    diesel::table! {
+        samples (id) {
+            id -> crate::diesel_impls::Uuid,
+        }
+    }
+
+    #[test]
+    fn test_postgres_created_between() {
+        use crate::diesel_impls::UuidExpressionMethods;
+
+        let Ok(database_url) = env::var("DATABASE_URL") else {
+            eprintln!("Skipping test_postgres_created_between: DATABASE_URL not set");
+            return;
+        };
+
+        let mut conn = PgConnection::establish(&database_url).unwrap();
+
+        diesel::sql_query("CREATE TEMPORARY TABLE samples (id UUID PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let at =
+            |seconds| std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(seconds);
+        let ids = [1_000, 2_000, 3_000].map(|seconds| Uuid::v7_from(at(seconds)).unwrap());
+
+        for id in ids {
+            diesel::insert_into(samples::table)
+                .values(samples::id.eq(id))
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let between = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_between(at(1_500), at(2_000)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(between, vec![ids[1]]);
+
+        let since = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_since(at(2_000)))
+            .order(samples::id)
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(since, vec![ids[1], ids[2]]);
+
+        let until = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_until(at(1_999)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(until, vec![ids[0]]);
+    }
+
+    #[derive(QueryableByName, Debug, PartialEq)]
+    struct RowArray {
+        #[diesel(sql_type = diesel::sql_types::Array<crate::diesel_impls::Uuid>)]
+        ids: Vec<Uuid>,
+    }
+
+    diesel::table! {
+        batches (id) {
+            id -> crate::diesel_impls::Uuid,
+            members -> Array<crate::diesel_impls::Uuid>,
+        }
+    }
+
+    #[test]
+    fn test_postgres_uuid_array() {
+        let Ok(database_url) = env::var("DATABASE_URL") else {
+            eprintln!("Skipping test_postgres_uuid_array: DATABASE_URL not set");
+            return;
+        };
+
+        let mut conn = PgConnection::establish(&database_url).unwrap();
+
+        diesel::sql_query(
+            "CREATE TEMPORARY TABLE batches (id UUID PRIMARY KEY, members UUID[] NOT NULL)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        let id = Uuid::utc_v7();
+        let members = vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::nil()];
+
+        diesel::insert_into(batches::table)
+            .values((batches::id.eq(id), batches::members.eq(&members)))
+            .execute(&mut conn)
+            .unwrap();
+
+        let loaded = batches::table
+            .select(batches::members)
+            .filter(batches::id.eq(id))
+            .get_result::<Vec<Uuid>>(&mut conn)
+            .unwrap();
+        assert_eq!(loaded, members);
+
+        let result = diesel::sql_query("SELECT $1 AS ids")
+            .bind::<diesel::sql_types::Array<crate::diesel_impls::Uuid>, _>(&members)
+            .get_result::<RowArray>(&mut conn)
+            .unwrap();
+        assert_eq!(result.ids, members);
+
+        let result = diesel::sql_query("SELECT ARRAY[]::uuid[] AS ids")
+            .get_result::<RowArray>(&mut conn)
+            .unwrap();
+        assert!(result.ids.is_empty());
+    }
+
+    #[test]
+    fn test_postgres_eq_any_uuid() {
+        use crate::diesel_impls::UuidExpressionMethods;
+
+        let Ok(database_url) = env::var("DATABASE_URL") else {
+            eprintln!("Skipping test_postgres_eq_any_uuid: DATABASE_URL not set");
+            return;
+        };
+
+        let mut conn = PgConnection::establish(&database_url).unwrap();
+
+        diesel::sql_query("CREATE TEMPORARY TABLE samples (id UUID PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let ids: Vec<crate::UuidV7> = (0..100).map(|_| crate::UuidV7::now()).collect();
+        diesel::insert_into(samples::table)
+            .values(
+                ids.iter()
+                    .map(|&id| samples::id.eq(Uuid::from(id)))
+                    .collect::<Vec<_>>(),
+            )
+            .execute(&mut conn)
+            .unwrap();
+
+        let wanted = ids.iter().copied().step_by(10);
+        let query = samples::table
+            .select(samples::id)
+            .filter(samples::id.eq_any_uuid(wanted.clone()))
+            .order(samples::id);
+        assert!(
+            diesel::debug_query::<diesel::pg::Pg, _>(&query)
+                .to_string()
+                .contains("= ANY($1)")
+        );
+        let found = query.load::<crate::UuidV7>(&mut conn).unwrap();
+        let mut expected = wanted.collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let none = samples::table
+            .select(samples::id)
+            .filter(samples::id.eq_any_uuid(Vec::<Uuid>::new()))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert!(none.is_empty());
+    }
 }
@@ -7,9 +7,7 @@ impl diesel::deserialize::FromSql<crate::diesel_impls::Uuid, diesel::sqlite::Sql
     fn from_sql(
         mut value: diesel::sqlite::SqliteValue<'_, '_, '_>,
     ) -> diesel::deserialize::Result<Self> {
-        uuid::Uuid::from_slice(value.read_blob())
-            .map_err(Into::into)
-            .map(Self::from)
+        crate::Uuid::from_slice(value.read_blob()).map_err(Into::into)
     }
 }
 
@@ -29,9 +27,7 @@ impl diesel::deserialize::FromSql<diesel::sql_types::Binary, diesel::sqlite::Sql
     fn from_sql(
         mut value: diesel::sqlite::SqliteValue<'_, '_, '_>,
     ) -> diesel::deserialize::Result<Self> {
-        uuid::Uuid::from_slice(value.read_blob())
-            .map_err(Into::into)
-            .map(Self::from)
+        crate::Uuid::from_slice(value.read_blob()).map_err(Into::into)
     }
 }
 
@@ -49,9 +45,7 @@ impl diesel::deserialize::FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlit
     fn from_sql(
         mut value: diesel::sqlite::SqliteValue<'_, '_, '_>,
     ) -> diesel::deserialize::Result<Self> {
-        uuid::Uuid::parse_str(value.read_text())
-            .map_err(Into::into)
-            .map(Self::from)
+        value.read_text().parse().map_err(Into::into)
     }
 }
 
@@ -65,6 +59,47 @@ impl diesel::serialize::ToSql<diesel::sql_types::Text, diesel::sqlite::Sqlite> f
     }
 }
 
+impl diesel::deserialize::FromSql<crate::diesel_impls::LenientUuid, diesel::sqlite::Sqlite>
+    for crate::Uuid
+{
+    fn from_sql(
+        mut value: diesel::sqlite::SqliteValue<'_, '_, '_>,
+    ) -> diesel::deserialize::Result<Self> {
+        match value.value_type() {
+            Some(diesel::sqlite::SqliteType::Text) => {
+                crate::Uuid::parse(value.read_text().trim()).map_err(Into::into)
+            }
+            Some(diesel::sqlite::SqliteType::Binary) => {
+                let bytes = value.read_blob();
+                if bytes.len() == 16 {
+                    crate::Uuid::from_slice(bytes).map_err(Into::into)
+                } else {
+                    // Some drivers store the textual representation as a BLOB.
+                    core::str::from_utf8(bytes)
+                        .map_err(Into::into)
+                        .and_then(|text| crate::Uuid::parse(text.trim()).map_err(Into::into))
+                }
+            }
+            storage_class => Err(format!(
+                "expected a UUID stored as a BLOB or as TEXT, found {storage_class:?}"
+            )
+            .into()),
+        }
+    }
+}
+
+impl diesel::serialize::ToSql<crate::diesel_impls::LenientUuid, diesel::sqlite::Sqlite>
+    for crate::Uuid
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, diesel::sqlite::Sqlite>,
+    ) -> diesel::serialize::Result {
+        out.set_value(self.as_bytes().as_slice());
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Uuid;
@@ -154,4 +189,386 @@ mod tests {
 
         assert_eq!(result.id, uuid);
     }
+
+    diesel::table! {
+        samples (id) {
+            id -> crate::diesel_impls::Uuid,
+        }
+    }
+
+    #[test]
+    fn test_sqlite_created_between() {
+        use crate::diesel_impls::UuidExpressionMethods;
+
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        diesel::sql_query("CREATE TABLE samples (id BLOB PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let at =
+            |seconds| std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(seconds);
+        let ids = [1_000, 2_000, 3_000].map(|seconds| Uuid::v7_from(at(seconds)).unwrap());
+
+        for id in ids {
+            diesel::insert_into(samples::table)
+                .values(samples::id.eq(id))
+                .execute(&mut conn)
+                .unwrap();
+        }
+
+        let between = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_between(at(1_500), at(2_000)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(between, vec![ids[1]]);
+
+        let since = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_since(at(2_000)))
+            .order(samples::id)
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(since, vec![ids[1], ids[2]]);
+
+        let until = samples::table
+            .select(samples::id)
+            .filter(samples::id.created_until(at(1_999)))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(until, vec![ids[0]]);
+    }
+
+    #[test]
+    fn test_sqlite_eq_any_uuid() {
+        use crate::diesel_impls::UuidExpressionMethods;
+
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        diesel::sql_query("CREATE TABLE samples (id BLOB PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        diesel::insert_into(samples::table)
+            .values(ids.map(|id| samples::id.eq(id)))
+            .execute(&mut conn)
+            .unwrap();
+
+        let mut found = samples::table
+            .select(samples::id)
+            .filter(samples::id.eq_any_uuid([ids[2], ids[0], Uuid::new_v4()]))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        found.sort();
+        let mut expected = vec![ids[0], ids[2]];
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let none = samples::table
+            .select(samples::id)
+            .filter(samples::id.eq_any_uuid(Vec::<Uuid>::new()))
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[derive(QueryableByName, Debug, PartialEq)]
+    struct RowV7 {
+        #[diesel(sql_type = crate::diesel_impls::Uuid)]
+        id: crate::UuidV7,
+    }
+
+    #[test]
+    fn test_sqlite_versioned() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        diesel::sql_query("CREATE TABLE test_table_v7 (id BLOB PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let v7 = crate::UuidV7::now();
+
+        diesel::sql_query("INSERT INTO test_table_v7 (id) VALUES (?)")
+            .bind::<crate::diesel_impls::Uuid, _>(v7)
+            .execute(&mut conn)
+            .unwrap();
+
+        let result = diesel::sql_query("SELECT id FROM test_table_v7")
+            .get_result::<RowV7>(&mut conn)
+            .unwrap();
+
+        assert_eq!(result.id, v7);
+
+        // A version 4 UUID written by someone else is rejected on read.
+        diesel::sql_query("DELETE FROM test_table_v7")
+            .execute(&mut conn)
+            .unwrap();
+        diesel::sql_query("INSERT INTO test_table_v7 (id) VALUES (?)")
+            .bind::<crate::diesel_impls::Uuid, _>(Uuid::new_v4())
+            .execute(&mut conn)
+            .unwrap();
+
+        assert!(
+            diesel::sql_query("SELECT id FROM test_table_v7")
+                .get_result::<RowV7>(&mut conn)
+                .is_err()
+        );
+    }
+
+    #[derive(QueryableByName, Debug)]
+    struct RowAny {
+        #[diesel(sql_type = crate::diesel_impls::Uuid)]
+        id: Uuid,
+    }
+
+    #[test]
+    fn test_sqlite_error_reports_length() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        let error = diesel::sql_query("SELECT X'0102' AS id")
+            .get_result::<RowAny>(&mut conn)
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("invalid UUID length: expected 16 bytes, found 2")
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct User;
+
+    impl crate::Prefix for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[derive(QueryableByName, Debug, PartialEq)]
+    struct RowPrefixed {
+        #[diesel(sql_type = crate::diesel_impls::Uuid)]
+        id: crate::PrefixedUuid<User>,
+    }
+
+    #[test]
+    fn test_sqlite_prefixed_stored_as_bytes() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        diesel::sql_query("CREATE TABLE test_table_prefixed (id BLOB PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let id = crate::PrefixedUuid::<User>::new(Uuid::utc_v7());
+
+        diesel::sql_query("INSERT INTO test_table_prefixed (id) VALUES (?)")
+            .bind::<crate::diesel_impls::Uuid, _>(id)
+            .execute(&mut conn)
+            .unwrap();
+
+        let result = diesel::sql_query("SELECT id FROM test_table_prefixed")
+            .get_result::<RowPrefixed>(&mut conn)
+            .unwrap();
+        assert_eq!(result.id, id);
+
+        let result = diesel::sql_query("SELECT id FROM test_table_prefixed")
+            .get_result::<RowAny>(&mut conn)
+            .unwrap();
+        assert_eq!(result.id, id.into_uuid());
+    }
+
+    #[derive(QueryableByName, Debug, PartialEq)]
+    struct RowStorage {
+        #[diesel(sql_type = diesel::sql_types::Text)]
+        storage: String,
+        #[diesel(sql_type = diesel::sql_types::Integer)]
+        length: i32,
+    }
+
+    diesel::table! {
+        legacy (rowid) {
+            rowid -> Integer,
+            id -> crate::diesel_impls::LenientUuid,
+        }
+    }
+
+    fn legacy_connection(rows: &str) -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query("CREATE TABLE legacy (id)")
+            .execute(&mut conn)
+            .unwrap();
+        diesel::sql_query(format!("INSERT INTO legacy (id) VALUES {rows}"))
+            .execute(&mut conn)
+            .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_sqlite_lenient_mixed_representations() {
+        let mut conn = legacy_connection(
+            "(X'67E5504410B1426F9247BB680E5FE0C8'),
+            ('67e55044-10b1-426f-9247-bb680e5fe0c8'),
+            ('67E5504410B1426F9247BB680E5FE0C8'),
+            ('{67e55044-10b1-426f-9247-bb680e5fe0c8}'),
+            ('urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8'),
+            (CAST('67e55044-10b1-426f-9247-bb680e5fe0c8' AS BLOB))",
+        );
+        let expected = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let other = Uuid::new_v4();
+        diesel::insert_into(legacy::table)
+            .values(legacy::id.eq(other))
+            .execute(&mut conn)
+            .unwrap();
+
+        let ids = legacy::table
+            .select(legacy::id)
+            .order(legacy::rowid)
+            .load::<Uuid>(&mut conn)
+            .unwrap();
+        assert_eq!(ids, [vec![expected; 6], vec![other]].concat());
+
+        // The strict SQL type still rejects the textual representations.
+        assert!(
+            diesel::sql_query("SELECT id FROM legacy")
+                .load::<RowAny>(&mut conn)
+                .is_err()
+        );
+
+        // Filters compare the stored values, which only match the canonical
+        // BLOBs, until the rows are rewritten.
+        let matching = legacy::table
+            .select(legacy::rowid)
+            .filter(legacy::id.eq(expected))
+            .load::<i32>(&mut conn)
+            .unwrap();
+        assert_eq!(matching, vec![1]);
+        for (rowid, id) in legacy::table
+            .select((legacy::rowid, legacy::id))
+            .load::<(i32, Uuid)>(&mut conn)
+            .unwrap()
+        {
+            diesel::update(legacy::table.filter(legacy::rowid.eq(rowid)))
+                .set(legacy::id.eq(id))
+                .execute(&mut conn)
+                .unwrap();
+        }
+        let matching = legacy::table
+            .select(legacy::rowid)
+            .filter(legacy::id.eq(expected))
+            .load::<i32>(&mut conn)
+            .unwrap();
+        assert_eq!(matching, vec![1, 2, 3, 4, 5, 6]);
+
+        // Writes are canonical.
+        let storage = diesel::sql_query(
+            "SELECT DISTINCT typeof(id) AS storage, length(id) AS length FROM legacy",
+        )
+        .load::<RowStorage>(&mut conn)
+        .unwrap();
+        assert_eq!(
+            storage,
+            vec![RowStorage {
+                storage: "blob".to_owned(),
+                length: 16
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sqlite_lenient_rejects_invalid_values() {
+        for row in ["('not a uuid')", "(X'0102')", "(42)", "(4.2)"] {
+            let mut conn = legacy_connection(row);
+            assert!(
+                legacy::table
+                    .select(legacy::id)
+                    .load::<Uuid>(&mut conn)
+                    .is_err(),
+                "{row} should be rejected"
+            );
+        }
+
+        let mut conn = legacy_connection("(42)");
+        let error = legacy::table
+            .select(legacy::id)
+            .load::<Uuid>(&mut conn)
+            .unwrap_err();
+        // The message of the field error is wrapped by the one naming the field.
+        let error = core::iter::successors(Some(&error as &dyn std::error::Error), |error| {
+            error.source()
+        })
+        .last()
+        .unwrap();
+        assert!(
+            error
+                .to_string()
+                .contains("expected a UUID stored as a BLOB or as TEXT")
+        );
+    }
+
+    #[test]
+    fn test_sqlite_lenient_versioned() {
+        let stored = crate::UuidV7::now();
+        let mut conn = legacy_connection(&format!("('{stored}')"));
+
+        let inserted = crate::UuidV7::now();
+        diesel::insert_into(legacy::table)
+            .values(legacy::id.eq(inserted))
+            .execute(&mut conn)
+            .unwrap();
+        let ids = legacy::table
+            .select(legacy::id)
+            .order(legacy::rowid)
+            .load::<crate::UuidV7>(&mut conn)
+            .unwrap();
+        assert_eq!(ids, vec![stored, inserted]);
+
+        let found = legacy::table
+            .select(legacy::id)
+            .filter(legacy::id.eq(inserted))
+            .get_result::<crate::UuidV7>(&mut conn)
+            .unwrap();
+        assert_eq!(found, inserted);
+
+        diesel::sql_query(format!(
+            "INSERT INTO legacy (id) VALUES ('{}')",
+            Uuid::new_v4()
+        ))
+        .execute(&mut conn)
+        .unwrap();
+        assert!(
+            legacy::table
+                .select(legacy::id)
+                .load::<crate::UuidV7>(&mut conn)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sqlite_lenient_prefixed() {
+        let mut conn = legacy_connection("('67e55044-10b1-426f-9247-bb680e5fe0c8')");
+
+        let id = crate::PrefixedUuid::<User>::new(Uuid::utc_v7());
+        diesel::insert_into(legacy::table)
+            .values(legacy::id.eq(id))
+            .execute(&mut conn)
+            .unwrap();
+
+        let found = legacy::table
+            .select(legacy::id)
+            .filter(legacy::id.eq(id))
+            .get_result::<crate::PrefixedUuid<User>>(&mut conn)
+            .unwrap();
+        assert_eq!(found, id);
+
+        let ids = legacy::table
+            .select(legacy::id)
+            .order(legacy::rowid)
+            .load::<crate::PrefixedUuid<User>>(&mut conn)
+            .unwrap();
+        assert_eq!(
+            ids[0].into_uuid().to_string(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(ids[1], id);
+    }
 }
@@ -0,0 +1,623 @@
+//! Compact textual encodings of a [`Uuid`], shorter than the 36 characters of
+//! its hyphenated representation.
+//!
+//! Each encoding comes with an adapter type implementing [`Display`] and
+//! [`FromStr`]:
+//!
+//! | Adapter             | Length | Preserves ordering |
+//! |---------------------|--------|--------------------|
+//! | [`Base58`]          | 22     | yes                |
+//! | [`Base62`]          | 22     | yes                |
+//! | [`CrockfordBase32`] | 26     | yes                |
+//! | [`Base64Url`]       | 22     | no                 |
+//!
+//! The encodings which preserve the ordering are fixed-width and use an
+//! alphabet sorted in ASCII order, so that the encoded version 7 UUIDs still
+//! sort lexicographically by creation time.
+//!
+//! [`Display`]: core::fmt::Display
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::{Error, Uuid};
+
+/// The digits of an encoding, and the table decoding them.
+struct Alphabet {
+    /// The digits, sorted by value.
+    digits: &'static [u8],
+    /// The value of each byte, or `u8::MAX` for the bytes which are not digits.
+    values: [u8; 256],
+}
+
+impl Alphabet {
+    /// Creates the alphabet of the provided digits, also decoding each of the
+    /// provided aliases as the digit it is paired with.
+    const fn new(digits: &'static [u8], aliases: &[(u8, u8)]) -> Self {
+        let mut values = [u8::MAX; 256];
+        let mut index = 0;
+        while index < digits.len() {
+            #[allow(clippy::cast_possible_truncation)]
+            let value = index as u8;
+            values[digits[index] as usize] = value;
+            index += 1;
+        }
+        let mut index = 0;
+        while index < aliases.len() {
+            let (alias, digit) = aliases[index];
+            values[alias as usize] = values[digit as usize];
+            index += 1;
+        }
+        Self { digits, values }
+    }
+
+    /// Returns the base of the encoding.
+    const fn base(&self) -> u128 {
+        self.digits.len() as u128
+    }
+
+    /// Writes the fixed-width big-endian representation of `value` into `buffer`.
+    fn encode(&self, mut value: u128, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut().rev() {
+            *byte = self.digits[usize::try_from(value % self.base()).expect("digit fits in usize")];
+            value /= self.base();
+        }
+    }
+
+    /// Returns the value of the digit at `index` in `input`.
+    fn digit(&self, input: &str, index: usize) -> Result<u128, Error> {
+        match self.values[usize::from(input.as_bytes()[index])] {
+            u8::MAX => Err(crate::parse::invalid_character(input, index)),
+            value => Ok(u128::from(value)),
+        }
+    }
+
+    /// Decodes the big-endian representation of a value from the first
+    /// `length` bytes of `input`, which may be followed by other characters.
+    fn decode(&self, input: &str, length: usize) -> Result<u128, Error> {
+        (0..length).try_fold(0_u128, |value, index| {
+            let digit = self.digit(input, index)?;
+            value
+                .checked_mul(self.base())
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(Error::Overflow)
+        })
+    }
+}
+
+/// The Bitcoin base58 alphabet, which omits `0`, `O`, `I` and `l`.
+static BASE58: Alphabet = Alphabet::new(
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    &[],
+);
+
+/// The base62 alphabet, made of the digits and the ASCII letters.
+static BASE62: Alphabet = Alphabet::new(
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    &[],
+);
+
+/// The Crockford base32 alphabet, which also decodes lowercase letters and the
+/// commonly mistaken `I`, `L` and `O`.
+static CROCKFORD_BASE32: Alphabet = Alphabet::new(
+    b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+    &[
+        (b'a', b'A'),
+        (b'b', b'B'),
+        (b'c', b'C'),
+        (b'd', b'D'),
+        (b'e', b'E'),
+        (b'f', b'F'),
+        (b'g', b'G'),
+        (b'h', b'H'),
+        (b'j', b'J'),
+        (b'k', b'K'),
+        (b'm', b'M'),
+        (b'n', b'N'),
+        (b'p', b'P'),
+        (b'q', b'Q'),
+        (b'r', b'R'),
+        (b's', b'S'),
+        (b't', b'T'),
+        (b'v', b'V'),
+        (b'w', b'W'),
+        (b'x', b'X'),
+        (b'y', b'Y'),
+        (b'z', b'Z'),
+        (b'I', b'1'),
+        (b'i', b'1'),
+        (b'L', b'1'),
+        (b'l', b'1'),
+        (b'O', b'0'),
+        (b'o', b'0'),
+    ],
+);
+
+/// The URL-safe base64 alphabet of RFC 4648.
+static BASE64_URL: Alphabet = Alphabet::new(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    &[],
+);
+
+/// Returns an error if `input` is not `length` bytes long.
+fn check_length(input: &str, expected: &'static [usize]) -> Result<(), Error> {
+    if expected.contains(&input.len()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidLength {
+            expected,
+            found: input.len(),
+        })
+    }
+}
+
+/// Defines an adapter type for a fixed-width encoding.
+macro_rules! encoding {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $length:literal, $method:ident, $parse:ident
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $(#[$meta])*
+        pub struct $name(Uuid);
+
+        impl $name {
+            /// The length of the encoded representation.
+            pub const LENGTH: usize = $length;
+
+            #[must_use]
+            /// Wraps the provided `Uuid`.
+            pub const fn from_uuid(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+
+            #[must_use]
+            /// Returns a reference to the wrapped `Uuid`.
+            pub const fn as_uuid(&self) -> &Uuid {
+                &self.0
+            }
+
+            #[must_use]
+            /// Returns the wrapped `Uuid`.
+            pub const fn into_uuid(self) -> Uuid {
+                self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.encode(&mut [0; $length]))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                check_length(s, &[$length])?;
+                Self::decode(s).map(Self)
+            }
+        }
+
+        impl From<Uuid> for $name {
+            fn from(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(encoded: $name) -> Self {
+                encoded.0
+            }
+        }
+
+        impl Uuid {
+            #[must_use]
+            #[doc = concat!("Returns an adapter encoding the `Uuid` as [`", stringify!($name), "`].")]
+            pub const fn $method(self) -> $name {
+                $name(self)
+            }
+
+            #[doc = concat!("Parses a `Uuid` encoded as [`", stringify!($name), "`].")]
+            ///
+            /// # Errors
+            ///
+            #[doc = concat!("* If the input is not ", stringify!($length), " characters long.")]
+            /// * If the input contains a character outside of the alphabet.
+            /// * If the encoded value does not fit in 128 bits.
+            pub fn $parse(input: &str) -> Result<Self, Error> {
+                input.parse::<$name>().map(Self::from)
+            }
+        }
+    };
+}
+
+encoding!(
+    /// Adapter encoding a [`Uuid`] in 22 characters of the Bitcoin base58 alphabet.
+    ///
+    /// The encoding is left-padded with `1`, the zero digit, so that it has a
+    /// fixed width and preserves the ordering of the UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::new_v4();
+    /// let encoded = uuid.base58().to_string();
+    /// assert_eq!(encoded.len(), 22);
+    /// assert_eq!(Uuid::parse_base58(&encoded), Ok(uuid));
+    /// ```
+    Base58,
+    22,
+    base58,
+    parse_base58
+);
+
+encoding!(
+    /// Adapter encoding a [`Uuid`] in 22 characters of the base62 alphabet
+    /// (`0-9`, `A-Z` and `a-z`).
+    ///
+    /// The encoding is left-padded with `0` so that it has a fixed width and
+    /// preserves the ordering of the UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::new_v4();
+    /// let encoded = uuid.base62().to_string();
+    /// assert_eq!(encoded.len(), 22);
+    /// assert_eq!(Uuid::parse_base62(&encoded), Ok(uuid));
+    /// ```
+    Base62,
+    22,
+    base62,
+    parse_base62
+);
+
+encoding!(
+    /// Adapter encoding a [`Uuid`] in 26 characters of the
+    /// [Crockford base32](https://www.crockford.com/base32.html) alphabet.
+    ///
+    /// The 128 bits are encoded in big-endian order, with the first character
+    /// holding the 3 most significant bits, which preserves the ordering of the
+    /// UUIDs. This is the same layout as a ULID. The encoder emits uppercase
+    /// letters, while the decoder also accepts lowercase letters and maps `I`
+    /// and `L` to `1` and `O` to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let first = Uuid::utc_v7();
+    /// let second = Uuid::monotonic_v7().max(first);
+    /// let encoded = first.crockford_base32().to_string();
+    /// assert_eq!(encoded.len(), 26);
+    /// assert!(encoded <= second.crockford_base32().to_string());
+    /// assert_eq!(Uuid::parse_crockford_base32(&encoded.to_lowercase()), Ok(first));
+    /// ```
+    CrockfordBase32,
+    26,
+    crockford_base32,
+    parse_crockford_base32
+);
+
+encoding!(
+    /// Adapter encoding a [`Uuid`] in 22 characters of the URL-safe base64
+    /// alphabet of RFC 4648, without padding.
+    ///
+    /// The alphabet is not sorted in ASCII order, so the encoding does not
+    /// preserve the ordering of the UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::from([0xFF; 16]);
+    /// assert_eq!(uuid.base64url().to_string(), "_____________________w");
+    /// assert_eq!(Uuid::parse_base64url("_____________________w"), Ok(uuid));
+    /// ```
+    Base64Url,
+    22,
+    base64url,
+    parse_base64url
+);
+
+impl Base58 {
+    /// Writes the encoded `Uuid` into the provided buffer, returning it as a string.
+    pub fn encode<'b>(&self, buffer: &'b mut [u8; Self::LENGTH]) -> &'b str {
+        BASE58.encode(self.0.as_u128(), buffer);
+        core::str::from_utf8(buffer).expect("the alphabet is ASCII")
+    }
+
+    /// Decodes the `Uuid` from an input of the right length.
+    fn decode(input: &str) -> Result<Uuid, Error> {
+        BASE58
+            .decode(input, input.len())
+            .map(|value| uuid::Uuid::from_u128(value).into())
+    }
+}
+
+impl Base62 {
+    /// Writes the encoded `Uuid` into the provided buffer, returning it as a string.
+    pub fn encode<'b>(&self, buffer: &'b mut [u8; Self::LENGTH]) -> &'b str {
+        BASE62.encode(self.0.as_u128(), buffer);
+        core::str::from_utf8(buffer).expect("the alphabet is ASCII")
+    }
+
+    /// Decodes the `Uuid` from an input of the right length.
+    fn decode(input: &str) -> Result<Uuid, Error> {
+        BASE62
+            .decode(input, input.len())
+            .map(|value| uuid::Uuid::from_u128(value).into())
+    }
+}
+
+impl CrockfordBase32 {
+    /// Writes the encoded `Uuid` into the provided buffer, returning it as a string.
+    pub fn encode<'b>(&self, buffer: &'b mut [u8; Self::LENGTH]) -> &'b str {
+        CROCKFORD_BASE32.encode(self.0.as_u128(), buffer);
+        core::str::from_utf8(buffer).expect("the alphabet is ASCII")
+    }
+
+    /// Decodes the `Uuid` from an input of the right length.
+    fn decode(input: &str) -> Result<Uuid, Error> {
+        CROCKFORD_BASE32
+            .decode(input, input.len())
+            .map(|value| uuid::Uuid::from_u128(value).into())
+    }
+}
+
+impl Uuid {
+    /// Parses a [ULID](https://github.com/ulid/spec) into the `Uuid` with the same 128 bits.
+    ///
+    /// A ULID starts with a 48-bit Unix timestamp in milliseconds, like a
+    /// version 7 UUID, and the conversion keeps the bits as they are, so the
+    /// converted ULIDs sort in the same order as the original ones. The
+    /// version and variant bits of the result are whatever the ULID holds.
+    ///
+    /// # Errors
+    ///
+    /// * If the input is not 26 characters long.
+    /// * If the input contains a character outside of the Crockford base32 alphabet.
+    /// * If the input encodes a value larger than the maximum ULID, i.e. starts with a digit above `7`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+    /// assert_eq!(uuid.to_string(), "01563e3a-b5d3-d676-4c61-efb99302bd5b");
+    /// assert_eq!(uuid.to_ulid_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    /// ```
+    pub fn from_ulid_str(input: &str) -> Result<Self, Error> {
+        Self::parse_crockford_base32(input)
+    }
+
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Returns the [ULID](https://github.com/ulid/spec) with the same 128 bits as the `Uuid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let first = Uuid::monotonic_v7();
+    /// let second = Uuid::monotonic_v7();
+    /// assert!(first.to_ulid_string() < second.to_ulid_string());
+    /// assert_eq!(Uuid::from_ulid_str(&first.to_ulid_string()), Ok(first));
+    /// ```
+    pub fn to_ulid_string(&self) -> String {
+        self.crockford_base32().to_string()
+    }
+}
+
+impl Base64Url {
+    /// Writes the encoded `Uuid` into the provided buffer, returning it as a string.
+    pub fn encode<'b>(&self, buffer: &'b mut [u8; Self::LENGTH]) -> &'b str {
+        let value = self.0.as_u128();
+        // The first 21 characters hold the 126 most significant bits, and the
+        // last one the 2 remaining bits followed by 4 bits of padding.
+        BASE64_URL.encode(value >> 2, &mut buffer[..21]);
+        BASE64_URL.encode((value & 0b11) << 4, &mut buffer[21..]);
+        core::str::from_utf8(buffer).expect("the alphabet is ASCII")
+    }
+
+    /// Decodes the `Uuid` from an input of the right length.
+    fn decode(input: &str) -> Result<Uuid, Error> {
+        let high = BASE64_URL.decode(input, 21)?;
+        let low = BASE64_URL.digit(input, 21)?;
+        if low & 0b1111 != 0 {
+            return Err(crate::parse::invalid_character(input, 21));
+        }
+        Ok(uuid::Uuid::from_u128((high << 2) | (low >> 4)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_roundtrips() {
+        for _ in 0..100 {
+            let uuid = Uuid::new_v4();
+            assert_eq!(Uuid::parse_base58(&uuid.base58().to_string()), Ok(uuid));
+            assert_eq!(Uuid::parse_base62(&uuid.base62().to_string()), Ok(uuid));
+            assert_eq!(
+                Uuid::parse_crockford_base32(&uuid.crockford_base32().to_string()),
+                Ok(uuid)
+            );
+            assert_eq!(
+                Uuid::parse_base64url(&uuid.base64url().to_string()),
+                Ok(uuid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        let nil = Uuid::default();
+        assert_eq!(nil.base58().to_string(), "1111111111111111111111");
+        assert_eq!(nil.base62().to_string(), "0000000000000000000000");
+        assert_eq!(
+            nil.crockford_base32().to_string(),
+            "00000000000000000000000000"
+        );
+        assert_eq!(nil.base64url().to_string(), "AAAAAAAAAAAAAAAAAAAAAA");
+
+        let max = Uuid::from([0xFF; 16]);
+        assert_eq!(max.base58().to_string(), "YcVfxkQb6JRzqk5kF2tNLv");
+        assert_eq!(max.base62().to_string(), "7n42DGM5Tflk9n8mt7Fhc7");
+        assert_eq!(
+            max.crockford_base32().to_string(),
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(max.base64url().to_string(), "_____________________w");
+
+        // The ULID specification example.
+        let ulid_example = Uuid::parse_crockford_base32("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(
+            ulid_example.to_string(),
+            "01563e3a-b5d3-d676-4c61-efb99302bd5b"
+        );
+
+        let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            .parse::<Uuid>()
+            .unwrap();
+        assert_eq!(uuid.base64url().to_string(), "Z-VQRBCxQm-SR7toDl_gyA");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_preserves_ordering() {
+        let mut uuids: Vec<Uuid> = (0..100).map(|_| Uuid::new_v4()).collect();
+        uuids.sort();
+        for pair in uuids.windows(2) {
+            assert!(pair[0].base58().to_string() < pair[1].base58().to_string());
+            assert!(pair[0].base62().to_string() < pair[1].base62().to_string());
+            assert!(
+                pair[0].crockford_base32().to_string() < pair[1].crockford_base32().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_crockford_aliases() {
+        let canonical = Uuid::parse_crockford_base32("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(
+            Uuid::parse_crockford_base32("o1arz3ndektsv4rrffq69g5fav"),
+            Ok(canonical)
+        );
+        assert_eq!(
+            Uuid::parse_crockford_base32("0LARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Uuid::parse_crockford_base32("0IARZ3NDEKTSV4RRFFQ69G5FAV")
+        );
+        assert_eq!(
+            Uuid::parse_crockford_base32("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+            Err(Error::InvalidCharacter {
+                character: 'U',
+                index: 25
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Uuid::parse_base58("111"),
+            Err(Error::InvalidLength {
+                expected: &[22],
+                found: 3
+            })
+        );
+        assert_eq!(
+            Uuid::parse_base58("0111111111111111111111"),
+            Err(Error::InvalidCharacter {
+                character: '0',
+                index: 0
+            })
+        );
+        assert_eq!(
+            Uuid::parse_base58("zzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Uuid::parse_base62("zzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Uuid::parse_crockford_base32("80000000000000000000000000"),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Uuid::parse_base64url("_____________________x"),
+            Err(Error::InvalidCharacter {
+                character: 'x',
+                index: 21
+            })
+        );
+        assert_eq!(
+            Uuid::parse_base64url("AAAAAAAAAAAAAAAAAAAAA="),
+            Err(Error::InvalidCharacter {
+                character: '=',
+                index: 21
+            })
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_input() {
+        // Multi-byte characters straddling the boundaries between the parts
+        // of an encoding are reported instead of splitting the string.
+        assert_eq!(
+            Uuid::parse_base64url("AAAAAAAAAAAAAAAAAAAAé"),
+            Err(Error::InvalidCharacter {
+                character: 'é',
+                index: 20
+            })
+        );
+        assert_eq!(
+            Uuid::parse_base64url("AAAAAAAAAAAAAAAAAAA€"),
+            Err(Error::InvalidCharacter {
+                character: '€',
+                index: 19
+            })
+        );
+        assert_eq!(
+            Uuid::parse_base58("11111111111111111111é"),
+            Err(Error::InvalidCharacter {
+                character: 'é',
+                index: 20
+            })
+        );
+        assert_eq!(
+            Uuid::parse_crockford_base32("000000000000000000000000é"),
+            Err(Error::InvalidCharacter {
+                character: 'é',
+                index: 24
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_encode_into_buffer() {
+        let uuid = Uuid::new_v4();
+        let mut buffer = [0; Base58::LENGTH];
+        assert_eq!(uuid.base58().encode(&mut buffer), uuid.base58().to_string());
+        let mut buffer = [0; CrockfordBase32::LENGTH];
+        assert_eq!(
+            uuid.crockford_base32().encode(&mut buffer),
+            uuid.crockford_base32().to_string()
+        );
+    }
+}
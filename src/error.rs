@@ -0,0 +1,249 @@
+//! Submodule defining the [`Error`] type returned by the fallible operations of this crate.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+/// The error type returned by the fallible operations of this crate.
+///
+/// Every decoding path (parsing, byte conversions, Serde, Diesel and Redis)
+/// reports the same errors, describing what was actually received.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Error, FixedClock, Uuid};
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Uuid::from_str("67e55044-10b1-426f-9247-bb680e5fe0cZ"),
+///     Err(Error::InvalidCharacter { character: 'Z', index: 35 })
+/// );
+/// assert_eq!(
+///     Uuid::from_slice(&[0; 15]),
+///     Err(Error::InvalidByteLength { found: 15 })
+/// );
+///
+/// let before_epoch = FixedClock::new(-1);
+/// assert_eq!(
+///     Uuid::try_v7_with_clock(&before_epoch),
+///     Err(Error::TimeBeforeUnixEpoch { unix_timestamp_nanos: -1 })
+/// );
+/// ```
+pub enum Error {
+    /// The clock reported a time before the Unix epoch, which cannot be
+    /// encoded in a timestamp-based UUID.
+    TimeBeforeUnixEpoch {
+        /// The time reported by the clock, in nanoseconds since the Unix epoch.
+        unix_timestamp_nanos: i128,
+    },
+    /// The clock reported a time that cannot be represented, such as one outside
+    /// of the 60-bit Gregorian timestamp of the version 1 and 6 UUIDs, from
+    /// 1582-10-15 to 5236-03-31.
+    TimestampOutOfRange {
+        /// The time reported by the clock, in nanoseconds since the Unix epoch.
+        unix_timestamp_nanos: i128,
+    },
+    /// The binary representation is not 16 bytes long.
+    InvalidByteLength {
+        /// The number of bytes received.
+        found: usize,
+    },
+    /// The textual representation does not have any of the accepted lengths.
+    InvalidLength {
+        /// The accepted lengths, in bytes.
+        expected: &'static [usize],
+        /// The length received, in bytes.
+        found: usize,
+    },
+    /// The textual representation is not valid UTF-8.
+    InvalidUtf8 {
+        /// The length of the longest valid UTF-8 prefix of the input, in bytes.
+        valid_up_to: usize,
+    },
+    /// The textual representation contains an unexpected character.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The position of the unexpected character, in bytes.
+        index: usize,
+    },
+    /// The encoded value does not fit in the 128 bits of a UUID.
+    Overflow,
+    /// The textual representation does not start with the expected prefix.
+    UnexpectedPrefix {
+        /// The prefix required by the type.
+        expected: &'static str,
+    },
+    /// The layout of a version 8 UUID has no field with the provided name.
+    UnknownField {
+        /// The name of the field.
+        name: &'static str,
+    },
+    /// The value does not fit in the bits of a field of a version 8 UUID.
+    FieldOverflow {
+        /// The name of the field.
+        name: &'static str,
+        /// The number of bits of the field.
+        width: u32,
+    },
+    /// The UUID is not of the RFC 9562 variant.
+    UnexpectedVariant {
+        /// The variant of the UUID.
+        found: uuid::Variant,
+    },
+    /// The UUID is not of the expected version.
+    UnexpectedVersion {
+        /// The version required by the type.
+        expected: uuid::Version,
+        /// The version of the UUID, if it is a known one.
+        found: Option<uuid::Version>,
+    },
+}
+
+impl From<core::str::Utf8Error> for Error {
+    fn from(error: core::str::Utf8Error) -> Self {
+        Self::InvalidUtf8 {
+            valid_up_to: error.valid_up_to(),
+        }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos,
+            } => write!(
+                f,
+                "time {unix_timestamp_nanos}ns is before the Unix epoch and cannot be encoded in a UUID"
+            ),
+            Self::TimestampOutOfRange {
+                unix_timestamp_nanos,
+            } => write!(
+                f,
+                "time {unix_timestamp_nanos}ns is outside of the range of the timestamp of a UUID"
+            ),
+            Self::InvalidByteLength { found } => {
+                write!(f, "invalid UUID length: expected 16 bytes, found {found}")
+            }
+            Self::InvalidLength { expected, found } => {
+                write!(f, "invalid UUID length: expected ")?;
+                for (position, length) in expected.iter().enumerate() {
+                    match position {
+                        0 => {}
+                        _ if position + 1 == expected.len() => write!(f, " or ")?,
+                        _ => write!(f, ", ")?,
+                    }
+                    write!(f, "{length}")?;
+                }
+                write!(f, " characters, found {found}")
+            }
+            Self::InvalidUtf8 { valid_up_to } => {
+                write!(f, "invalid UTF-8 in UUID after {valid_up_to} bytes")
+            }
+            Self::InvalidCharacter { character, index } => {
+                write!(
+                    f,
+                    "invalid character {character:?} in UUID at position {index}"
+                )
+            }
+            Self::Overflow => write!(f, "encoded UUID does not fit in 128 bits"),
+            Self::UnexpectedPrefix { expected } => {
+                write!(f, "expected a UUID prefixed with {expected:?}")
+            }
+            Self::UnknownField { name } => {
+                write!(f, "unknown field {name:?} in the UUIDv8 layout")
+            }
+            Self::FieldOverflow { name, width } => {
+                write!(
+                    f,
+                    "value does not fit in the {width} bits of field {name:?}"
+                )
+            }
+            Self::UnexpectedVariant { found } => {
+                write!(
+                    f,
+                    "expected a UUID of the RFC 9562 variant, found {found:?}"
+                )
+            }
+            Self::UnexpectedVersion { expected, found } => match found {
+                Some(found) => write!(f, "expected a {expected:?} UUID, found a {found:?} UUID"),
+                None => write!(f, "expected a {expected:?} UUID, found an unknown version"),
+            },
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Error::TimestampOutOfRange {
+                unix_timestamp_nanos: -1
+            }
+            .to_string(),
+            "time -1ns is outside of the range of the timestamp of a UUID"
+        );
+        assert_eq!(
+            Error::InvalidLength {
+                expected: &crate::parse::TEXT_LENGTHS,
+                found: 12
+            }
+            .to_string(),
+            "invalid UUID length: expected 32, 36, 38 or 45 characters, found 12"
+        );
+        assert_eq!(
+            Error::InvalidByteLength { found: 15 }.to_string(),
+            "invalid UUID length: expected 16 bytes, found 15"
+        );
+        assert_eq!(
+            Error::InvalidCharacter {
+                character: 'Z',
+                index: 35
+            }
+            .to_string(),
+            "invalid character 'Z' in UUID at position 35"
+        );
+        assert_eq!(
+            Error::InvalidUtf8 { valid_up_to: 3 }.to_string(),
+            "invalid UTF-8 in UUID after 3 bytes"
+        );
+        assert_eq!(
+            Error::Overflow.to_string(),
+            "encoded UUID does not fit in 128 bits"
+        );
+        assert_eq!(
+            Error::UnexpectedPrefix { expected: "user" }.to_string(),
+            "expected a UUID prefixed with \"user\""
+        );
+        assert_eq!(
+            Error::FieldOverflow {
+                name: "shard",
+                width: 10
+            }
+            .to_string(),
+            "value does not fit in the 10 bits of field \"shard\""
+        );
+        assert_eq!(
+            Error::UnexpectedVersion {
+                expected: uuid::Version::SortRand,
+                found: Some(uuid::Version::Random)
+            }
+            .to_string(),
+            "expected a SortRand UUID, found a Random UUID"
+        );
+    }
+
+    #[test]
+    fn test_from_utf8_error() {
+        let bytes = vec![b'a', 0xff];
+        let error = core::str::from_utf8(&bytes).unwrap_err();
+        assert_eq!(Error::from(error), Error::InvalidUtf8 { valid_up_to: 1 });
+    }
+}
@@ -0,0 +1,350 @@
+//! Submodule defining the [`UuidGenerator`] trait, which lets the code creating
+//! identifiers receive its source of UUIDs instead of calling the constructors
+//! of [`Uuid`] directly.
+//!
+//! Production code can be handed a [`V4Generator`], a [`UtcV7Generator`] or a
+//! monotonic [`V7Generator`](crate::V7Generator), while tests pin the
+//! identifiers with a [`SequenceGenerator`] or a seeded generator. Any closure
+//! returning a `Uuid` can be adapted with a [`FnGenerator`].
+
+use crate::Uuid;
+
+/// A source of UUIDs, usable as a `&dyn UuidGenerator`.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{SequenceGenerator, Uuid, UuidGenerator, V4Generator};
+///
+/// struct Repository<'a> {
+///     ids: &'a dyn UuidGenerator,
+/// }
+///
+/// impl Repository<'_> {
+///     fn insert(&self) -> Uuid {
+///         self.ids.generate()
+///     }
+/// }
+///
+/// let production = Repository { ids: &V4Generator };
+/// assert_eq!(production.insert().get_version(), Some(uuid::Version::Random));
+///
+/// let expected = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+/// let test = Repository { ids: &SequenceGenerator::new([expected]) };
+/// assert_eq!(test.insert(), expected);
+/// ```
+pub trait UuidGenerator {
+    /// Returns the next `Uuid`.
+    fn generate(&self) -> Uuid;
+
+    #[cfg(feature = "std")]
+    /// Converts the generator into a boxed closure, as expected by the
+    /// registration of the SQLite functions declared with Diesel.
+    ///
+    /// The closure is marked as unwind safe: the generators of this crate
+    /// keep a consistent state when they panic, and custom generators are
+    /// expected to do the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{UuidGenerator, V7Generator};
+    /// use std::sync::Arc;
+    ///
+    /// let generator: Arc<dyn UuidGenerator + Send + Sync> = Arc::new(V7Generator::new());
+    /// let function = generator.into_boxed_fn();
+    /// assert!(function() < function());
+    /// ```
+    fn into_boxed_fn(self) -> Box<dyn Fn() -> Uuid + Send + std::panic::UnwindSafe>
+    where
+        Self: Sized + Send + 'static,
+    {
+        let generator = std::panic::AssertUnwindSafe(self);
+        Box::new(move || generator.generate())
+    }
+}
+
+impl<G: UuidGenerator + ?Sized> UuidGenerator for &G {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: UuidGenerator + ?Sized> UuidGenerator for Box<G> {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: UuidGenerator + ?Sized> UuidGenerator for std::sync::Arc<G> {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// A generator of random version 4 UUIDs, created with [`Uuid::new_v4`].
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{UuidGenerator, V4Generator};
+///
+/// assert_ne!(V4Generator.generate(), V4Generator.generate());
+/// ```
+pub struct V4Generator;
+
+#[cfg(feature = "std")]
+impl UuidGenerator for V4Generator {
+    fn generate(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// A generator of version 7 UUIDs with the current UTC timestamp, created
+/// with [`Uuid::utc_v7`].
+///
+/// Use a [`V7Generator`](crate::V7Generator) instead when the UUIDs must be
+/// strictly increasing within the same millisecond.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{UtcV7Generator, UuidGenerator};
+///
+/// let uuid = UtcV7Generator.generate();
+/// assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+/// ```
+pub struct UtcV7Generator;
+
+#[cfg(feature = "std")]
+impl UuidGenerator for UtcV7Generator {
+    fn generate(&self) -> Uuid {
+        Uuid::utc_v7()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: crate::Clock> UuidGenerator for crate::V7Generator<C> {
+    fn generate(&self) -> Uuid {
+        Self::generate(self)
+    }
+}
+
+#[cfg(feature = "deterministic")]
+impl<C: crate::Clock> UuidGenerator for crate::SeededGenerator<C> {
+    /// Creates the next version 4 `Uuid` of the sequence, see
+    /// [`SeededGenerator::new_v4`](crate::SeededGenerator::new_v4).
+    fn generate(&self) -> Uuid {
+        self.new_v4()
+    }
+}
+
+#[cfg(feature = "deterministic")]
+impl<C: crate::Clock> UuidGenerator for crate::SeededV7Generator<C> {
+    /// Creates the next version 7 `Uuid` of the sequence, see
+    /// [`SeededGenerator::new_v7`](crate::SeededGenerator::new_v7).
+    fn generate(&self) -> Uuid {
+        self.new_v7()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+/// A generator returning the provided UUIDs, in order.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{SequenceGenerator, Uuid, UuidGenerator};
+///
+/// let generator = SequenceGenerator::new([Uuid::nil(), Uuid::max()]);
+/// assert_eq!(generator.remaining(), 2);
+/// assert_eq!(generator.generate(), Uuid::nil());
+/// assert_eq!(generator.generate(), Uuid::max());
+/// assert_eq!(generator.remaining(), 0);
+/// ```
+pub struct SequenceGenerator {
+    /// The UUIDs to return.
+    uuids: Box<[Uuid]>,
+    /// The index of the next UUID to return.
+    next: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(feature = "std")]
+impl SequenceGenerator {
+    #[must_use]
+    /// Creates a generator returning the provided UUIDs, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{SequenceGenerator, Uuid};
+    ///
+    /// let generator = SequenceGenerator::new((0..10).map(|n| Uuid::from_u128(n)));
+    /// assert_eq!(generator.remaining(), 10);
+    /// ```
+    pub fn new<I: IntoIterator<Item = Uuid>>(uuids: I) -> Self {
+        Self {
+            uuids: uuids.into_iter().collect(),
+            next: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    #[must_use]
+    /// Returns the number of UUIDs left in the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{SequenceGenerator, Uuid, UuidGenerator};
+    ///
+    /// let generator = SequenceGenerator::new([Uuid::nil()]);
+    /// let _ = generator.generate();
+    /// assert_eq!(generator.remaining(), 0);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.uuids.len().saturating_sub(
+            self.next
+                .load(std::sync::atomic::Ordering::Relaxed)
+                .min(self.uuids.len()),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl UuidGenerator for SequenceGenerator {
+    /// Returns the next `Uuid` of the sequence.
+    ///
+    /// # Panics
+    ///
+    /// * If every `Uuid` of the sequence has already been returned.
+    fn generate(&self) -> Uuid {
+        let index = self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        *self.uuids.get(index).unwrap_or_else(|| {
+            panic!(
+                "SequenceGenerator exhausted after {} UUIDs",
+                self.uuids.len()
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// An adapter turning a closure returning a `Uuid` into a [`UuidGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// use core::cell::Cell;
+/// use rosetta_uuid::{FnGenerator, Uuid, UuidGenerator};
+///
+/// let counter = Cell::new(0_u128);
+/// let generator = FnGenerator::new(|| Uuid::from_u128(counter.replace(counter.get() + 1)));
+/// assert_eq!(generator.generate(), Uuid::from_u128(0));
+/// assert_eq!(generator.generate(), Uuid::from_u128(1));
+/// ```
+pub struct FnGenerator<F>(F);
+
+impl<F: Fn() -> Uuid> FnGenerator<F> {
+    #[must_use]
+    /// Creates a generator calling the provided closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FnGenerator, Uuid, UuidGenerator};
+    ///
+    /// let generator = FnGenerator::new(|| Uuid::nil());
+    /// assert!(generator.generate().is_nil());
+    /// ```
+    pub const fn new(function: F) -> Self {
+        Self(function)
+    }
+}
+
+impl<F: Fn() -> Uuid> UuidGenerator for FnGenerator<F> {
+    fn generate(&self) -> Uuid {
+        (self.0)()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn take_two(generator: &dyn UuidGenerator) -> (Uuid, Uuid) {
+        (generator.generate(), generator.generate())
+    }
+
+    #[test]
+    fn test_dyn_generators() {
+        let (first, second) = take_two(&V4Generator);
+        assert_ne!(first, second);
+        assert_eq!(first.get_version(), Some(uuid::Version::Random));
+
+        let (first, second) = take_two(&UtcV7Generator);
+        assert_eq!(first.get_version(), Some(uuid::Version::SortRand));
+        assert!(
+            first.get_timestamp().unwrap().to_unix() <= second.get_timestamp().unwrap().to_unix()
+        );
+
+        let (first, second) = take_two(&crate::V7Generator::new());
+        assert!(first < second);
+
+        let boxed: Box<dyn UuidGenerator> = Box::new(V4Generator);
+        assert_eq!(
+            take_two(&boxed).0.get_version(),
+            Some(uuid::Version::Random)
+        );
+    }
+
+    #[test]
+    fn test_sequence_generator() {
+        let uuids = [Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3)];
+        let generator = SequenceGenerator::new(uuids);
+        assert_eq!(take_two(&generator), (uuids[0], uuids[1]));
+        assert_eq!(generator.remaining(), 1);
+        assert_eq!(generator.generate(), uuids[2]);
+        assert_eq!(generator.remaining(), 0);
+        assert!(std::panic::catch_unwind(|| generator.generate()).is_err());
+        assert_eq!(generator.remaining(), 0);
+    }
+
+    #[test]
+    fn test_into_boxed_fn() {
+        let generator: Arc<dyn UuidGenerator + Send + Sync> = Arc::new(SequenceGenerator::new([
+            Uuid::from_u128(1),
+            Uuid::from_u128(2),
+        ]));
+        let function = Arc::clone(&generator).into_boxed_fn();
+        assert_eq!(function(), Uuid::from_u128(1));
+        assert_eq!(generator.generate(), Uuid::from_u128(2));
+    }
+
+    #[test]
+    #[cfg(feature = "deterministic")]
+    fn test_seeded_generator() {
+        let first = take_two(&crate::SeededGenerator::new(42));
+        let second = take_two(&crate::SeededGenerator::new(42));
+        assert_eq!(first, second);
+        assert_eq!(first.0.get_version(), Some(uuid::Version::Random));
+
+        let first = take_two(&crate::SeededGenerator::new(42).v7());
+        let second = take_two(&crate::SeededGenerator::new(42).v7());
+        assert_eq!(first, second);
+        assert_eq!(first.0.get_version(), Some(uuid::Version::SortRand));
+        assert!(first.0 < first.1);
+        assert_eq!(
+            first.0,
+            crate::SeededGenerator::new(42).new_v7(),
+            "the adapter draws the same sequence as the generator"
+        );
+    }
+}
@@ -0,0 +1,425 @@
+//! Submodule implementing the Gregorian-time-based (version 1 and 6) UUIDs.
+//!
+//! Both versions hold the same fields: a 60-bit count of 100-nanosecond
+//! intervals since the Gregorian epoch (1582-10-15), a 14-bit clock sequence
+//! and a 48-bit [`NodeId`]. Version 1 stores the least significant bits of the
+//! timestamp first, so it does not sort by creation time, while version 6
+//! stores them from the most significant one. [`Uuid::to_v6`] and
+//! [`Uuid::to_v1`] reorder the timestamp bits without losing any of them.
+
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicU32, Ordering};
+
+#[cfg(feature = "std")]
+use crate::{Clock, SystemClock};
+use crate::{Error, Uuid};
+
+#[cfg(feature = "std")]
+/// Number of 100-nanosecond intervals between the Gregorian and Unix epochs.
+const TICKS_BETWEEN_EPOCHS: i128 = 0x01B2_1DD2_1381_4000;
+
+#[cfg(feature = "std")]
+/// Number of bits of the Gregorian timestamp.
+const TIMESTAMP_BITS: u32 = 60;
+
+#[cfg(feature = "std")]
+/// Mask of the 14 bits of the clock sequence.
+const CLOCK_SEQUENCE_MASK: u32 = 0x3FFF;
+
+#[cfg(feature = "std")]
+/// Marker of a clock sequence which was not yet randomly initialized.
+const UNINITIALIZED: u32 = u32::MAX;
+
+#[cfg(feature = "std")]
+/// The last clock sequence used by this process.
+static CLOCK_SEQUENCE: AtomicU32 = AtomicU32::new(UNINITIALIZED);
+
+#[cfg(feature = "std")]
+/// Returns the next clock sequence, starting from a random one.
+///
+/// Incrementing the clock sequence for every UUID keeps the UUIDs created
+/// within the same 100-nanosecond interval, or after the clock moved
+/// backwards, distinct from each other.
+fn next_clock_sequence() -> u16 {
+    let mut next = 0;
+    let _ = CLOCK_SEQUENCE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+        next = if current == UNINITIALIZED {
+            let random = uuid::Uuid::new_v4().as_bytes()[..2]
+                .try_into()
+                .map(u16::from_be_bytes);
+            u32::from(random.expect("two bytes")) & CLOCK_SEQUENCE_MASK
+        } else {
+            current.wrapping_add(1) & CLOCK_SEQUENCE_MASK
+        };
+        Some(next)
+    });
+    u16::try_from(next).expect("the clock sequence has 14 bits")
+}
+
+#[cfg(feature = "std")]
+/// Returns the Gregorian timestamp of the time reported by the provided clock.
+fn gregorian_ticks<C: Clock + ?Sized>(clock: &C) -> Result<u64, Error> {
+    let unix_timestamp_nanos = clock.unix_timestamp_nanos();
+    let ticks = unix_timestamp_nanos.div_euclid(100) + TICKS_BETWEEN_EPOCHS;
+    u64::try_from(ticks)
+        .ok()
+        .filter(|ticks| ticks >> TIMESTAMP_BITS == 0)
+        .ok_or(Error::TimestampOutOfRange {
+            unix_timestamp_nanos,
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The 48-bit node identifier of the version 1 and 6 UUIDs.
+///
+/// It is either configured, traditionally as the MAC address of the machine,
+/// or random, in which case the multicast bit is set so that it cannot collide
+/// with a real MAC address, as
+/// [RFC 9562, section 6.10](https://www.rfc-editor.org/rfc/rfc9562#section-6.10)
+/// recommends.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::NodeId;
+///
+/// let configured = NodeId::new([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+/// assert_eq!(configured.as_bytes(), &[0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+///
+/// let random = NodeId::random();
+/// assert_eq!(random.as_bytes()[0] & 0x01, 0x01);
+/// ```
+pub struct NodeId([u8; 6]);
+
+impl NodeId {
+    #[must_use]
+    /// Creates the node identifier with the provided bytes.
+    pub const fn new(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Creates a random node identifier, with the multicast bit set.
+    pub fn random() -> Self {
+        let mut bytes = [0; 6];
+        bytes.copy_from_slice(&uuid::Uuid::new_v4().as_bytes()[10..]);
+        bytes[0] |= 0x01;
+        Self(bytes)
+    }
+
+    #[must_use]
+    /// Returns the bytes of the node identifier.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+}
+
+impl From<[u8; 6]> for NodeId {
+    fn from(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<NodeId> for [u8; 6] {
+    fn from(node_id: NodeId) -> Self {
+        node_id.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl Uuid {
+    #[must_use]
+    /// Creates a new version 1 `Uuid` with the current UTC timestamp and the provided node identifier.
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time which cannot be encoded, see [`Uuid::try_v1_with_clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{NodeId, Uuid};
+    ///
+    /// let node_id = NodeId::random();
+    /// let uuid = Uuid::new_v1(node_id);
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::Mac));
+    /// assert_eq!(uuid.get_node_id(), Some(*node_id.as_bytes()));
+    /// ```
+    pub fn new_v1(node_id: NodeId) -> Self {
+        Self::try_v1_with_clock(&SystemClock, node_id).expect("Time out of range")
+    }
+
+    /// Creates a new version 1 `Uuid` with the time reported by the provided
+    /// [`Clock`] and the provided node identifier.
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Gregorian epoch (1582-10-15), or after 5236.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, NodeId, Uuid};
+    ///
+    /// let clock = FixedClock::new(1_700_000_000_000_000_000);
+    /// let uuid = Uuid::try_v1_with_clock(&clock, NodeId::random()).unwrap();
+    /// assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (1_700_000_000, 0));
+    /// ```
+    pub fn try_v1_with_clock<C: Clock + ?Sized>(clock: &C, node_id: NodeId) -> Result<Self, Error> {
+        Ok(uuid::Builder::from_gregorian_timestamp(
+            gregorian_ticks(clock)?,
+            next_clock_sequence(),
+            node_id.as_bytes(),
+        )
+        .into_uuid()
+        .into())
+    }
+
+    #[must_use]
+    /// Creates a new version 6 `Uuid` with the current UTC timestamp and the provided node identifier.
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time which cannot be encoded, see [`Uuid::try_v6_with_clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{NodeId, Uuid};
+    ///
+    /// let uuid = Uuid::new_v6(NodeId::random());
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::SortMac));
+    /// ```
+    pub fn new_v6(node_id: NodeId) -> Self {
+        Self::try_v6_with_clock(&SystemClock, node_id).expect("Time out of range")
+    }
+
+    /// Creates a new version 6 `Uuid` with the time reported by the provided
+    /// [`Clock`] and the provided node identifier.
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Gregorian epoch (1582-10-15), or after 5236.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, FixedClock, NodeId, Uuid};
+    ///
+    /// let clock = FixedClock::new(1_700_000_000_000_000_000);
+    /// let uuid = Uuid::try_v6_with_clock(&clock, NodeId::random()).unwrap();
+    /// assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (1_700_000_000, 0));
+    ///
+    /// let before_gregorian_epoch = FixedClock::new(-20_000_000_000_000_000_000);
+    /// assert!(Uuid::try_v6_with_clock(&before_gregorian_epoch, NodeId::random()).is_err());
+    /// ```
+    pub fn try_v6_with_clock<C: Clock + ?Sized>(clock: &C, node_id: NodeId) -> Result<Self, Error> {
+        Ok(uuid::Builder::from_sorted_gregorian_timestamp(
+            gregorian_ticks(clock)?,
+            next_clock_sequence(),
+            node_id.as_bytes(),
+        )
+        .into_uuid()
+        .into())
+    }
+}
+
+impl Uuid {
+    /// Reorders the timestamp of a version 1 `Uuid` into the equivalent version 6 `Uuid`.
+    ///
+    /// The clock sequence and node identifier are kept as they are, and a
+    /// version 6 `Uuid` is returned unchanged. The conversion is lossless, and
+    /// [`Uuid::to_v1`] reverts it.
+    ///
+    /// # Errors
+    ///
+    /// * If the `Uuid` is not of the RFC 9562 variant.
+    /// * If the `Uuid` is neither a version 1 nor a version 6 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let v1: Uuid = "c232ab00-9414-11ec-b3c8-9f6bdeced846".parse().unwrap();
+    /// let v6 = v1.to_v6().unwrap();
+    /// assert_eq!(v6.to_string(), "1ec9414c-232a-6b00-b3c8-9f6bdeced846");
+    /// assert_eq!(v6.to_v1(), Ok(v1));
+    /// ```
+    pub fn to_v6(&self) -> Result<Self, Error> {
+        if self.gregorian_version(uuid::Version::Mac)? == uuid::Version::SortMac {
+            Ok(*self)
+        } else {
+            let value = self.as_u128();
+            let time_low = value >> 96;
+            let time_mid = (value >> 80) & 0xFFFF;
+            let time_high = (value >> 64) & 0x0FFF;
+            let ticks = (time_high << 48) | (time_mid << 32) | time_low;
+            let reordered = ((ticks >> 12) << 80) | (0x6 << 76) | ((ticks & 0x0FFF) << 64);
+            Ok(uuid::Uuid::from_u128(reordered | (value & u128::from(u64::MAX))).into())
+        }
+    }
+
+    /// Reorders the timestamp of a version 6 `Uuid` into the equivalent version 1 `Uuid`.
+    ///
+    /// The clock sequence and node identifier are kept as they are, and a
+    /// version 1 `Uuid` is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * If the `Uuid` is not of the RFC 9562 variant.
+    /// * If the `Uuid` is neither a version 1 nor a version 6 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{NodeId, Uuid};
+    ///
+    /// let v6 = Uuid::new_v6(NodeId::random());
+    /// let v1 = v6.to_v1().unwrap();
+    /// assert_eq!(v1.get_version(), Some(uuid::Version::Mac));
+    /// assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+    /// ```
+    pub fn to_v1(&self) -> Result<Self, Error> {
+        if self.gregorian_version(uuid::Version::SortMac)? == uuid::Version::Mac {
+            Ok(*self)
+        } else {
+            let value = self.as_u128();
+            let ticks = ((value >> 80) << 12) | ((value >> 64) & 0x0FFF);
+            let time_low = ticks & 0xFFFF_FFFF;
+            let time_mid = (ticks >> 32) & 0xFFFF;
+            let time_high = ticks >> 48;
+            let reordered = (time_low << 96) | (time_mid << 80) | (0x1 << 76) | (time_high << 64);
+            Ok(uuid::Uuid::from_u128(reordered | (value & u128::from(u64::MAX))).into())
+        }
+    }
+
+    /// Returns the version of a version 1 or 6 `Uuid`, reporting the `expected`
+    /// version in the error otherwise.
+    fn gregorian_version(&self, expected: uuid::Version) -> Result<uuid::Version, Error> {
+        let variant = self.get_variant();
+        if variant != uuid::Variant::RFC4122 {
+            return Err(Error::UnexpectedVariant { found: variant });
+        }
+        match self.get_version() {
+            Some(version @ (uuid::Version::Mac | uuid::Version::SortMac)) => Ok(version),
+            found => Err(Error::UnexpectedVersion { expected, found }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::FixedClock;
+    #[cfg(feature = "std")]
+    use alloc::string::ToString;
+
+    #[cfg(feature = "std")]
+    const NODE_ID: NodeId = NodeId::new([0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46]);
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rfc_vectors() {
+        // RFC 9562, appendices A.1 and A.5: Tuesday, February 22, 2022 2:22:22.00 PM GMT-05:00.
+        let clock = FixedClock::new(1_645_557_742_000_000_000);
+        let v1 = Uuid::try_v1_with_clock(&clock, NODE_ID).unwrap();
+        let v6 = Uuid::try_v6_with_clock(&clock, NODE_ID).unwrap();
+        assert_eq!(&v1.to_string()[..18], "c232ab00-9414-11ec");
+        assert_eq!(&v6.to_string()[..18], "1ec9414c-232a-6b00");
+        assert_eq!(&v1.to_string()[23..], "-9f6bdeced846");
+    }
+
+    #[test]
+    fn test_rfc_vectors_reordering() {
+        // RFC 9562, appendices A.1 and A.5.
+        let v1: Uuid = "c232ab00-9414-11ec-b3c8-9f6bdeced846".parse().unwrap();
+        let v6: Uuid = "1ec9414c-232a-6b00-b3c8-9f6bdeced846".parse().unwrap();
+        assert_eq!(v1.to_v6(), Ok(v6));
+        assert_eq!(v6.to_v1(), Ok(v1));
+        assert_eq!(v1.to_v1(), Ok(v1));
+        assert_eq!(v6.to_v6(), Ok(v6));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_reordering_is_lossless() {
+        for _ in 0..100 {
+            let v1 = Uuid::new_v1(NodeId::random());
+            let v6 = v1.to_v6().unwrap();
+            assert_eq!(v6.get_version(), Some(uuid::Version::SortMac));
+            assert_eq!(v6.get_timestamp(), v1.get_timestamp());
+            assert_eq!(v6.get_node_id(), v1.get_node_id());
+            assert_eq!(v6.as_bytes()[8..], v1.as_bytes()[8..]);
+            assert_eq!(v6.to_v1(), Ok(v1));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_converted_v1_sort_by_time() {
+        let v1s: Vec<Uuid> = (0..100_i128)
+            .map(|step| {
+                // Spread over the whole range of the timestamp fields.
+                let clock = FixedClock::new(1_600_000_000_000_000_000 + step * 12_345_678_901_234);
+                Uuid::try_v1_with_clock(&clock, NODE_ID).unwrap()
+            })
+            .collect();
+        let v6s: Vec<Uuid> = v1s.iter().map(|v1| v1.to_v6().unwrap()).collect();
+        assert!(v6s.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(!v1s.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_clock_sequence_distinguishes_same_tick() {
+        let clock = FixedClock::new(1_700_000_000_000_000_000);
+        let first = Uuid::try_v6_with_clock(&clock, NODE_ID).unwrap();
+        let second = Uuid::try_v6_with_clock(&clock, NODE_ID).unwrap();
+        assert_eq!(
+            first.get_timestamp().unwrap().to_gregorian().0,
+            second.get_timestamp().unwrap().to_gregorian().0
+        );
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_errors() {
+        assert_eq!(
+            Uuid::new_v4().to_v6(),
+            Err(Error::UnexpectedVersion {
+                expected: uuid::Version::Mac,
+                found: Some(uuid::Version::Random)
+            })
+        );
+        assert_eq!(
+            Uuid::utc_v7().to_v1(),
+            Err(Error::UnexpectedVersion {
+                expected: uuid::Version::SortMac,
+                found: Some(uuid::Version::SortRand)
+            })
+        );
+        assert!(Uuid::default().to_v6().is_err());
+
+        let before = FixedClock::new(-12_219_292_800_000_000_001);
+        assert_eq!(
+            Uuid::try_v1_with_clock(&before, NODE_ID),
+            Err(Error::TimestampOutOfRange {
+                unix_timestamp_nanos: -12_219_292_800_000_000_001
+            })
+        );
+        let epoch = FixedClock::new(-12_219_292_800_000_000_000);
+        assert_eq!(
+            Uuid::try_v1_with_clock(&epoch, NODE_ID)
+                .unwrap()
+                .get_timestamp()
+                .unwrap()
+                .to_gregorian()
+                .0,
+            0
+        );
+    }
+}
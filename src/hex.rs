@@ -0,0 +1,534 @@
+//! Submodule implementing the vectorized decoding and encoding of the
+//! hexadecimal representations of a UUID.
+//!
+//! The digits are processed with SSE2, or AVX2 when the CPU supports it, on
+//! `x86_64`, with NEON on `aarch64`, and with a portable scalar fallback on
+//! every other target, including `wasm32-unknown-unknown`.
+//!
+//! The decoders only tell whether the digits are valid: the parser falls back
+//! to its scalar loop to describe the rejected inputs, so that the errors are
+//! the same on every target.
+
+use crate::{Error, Uuid};
+
+/// The lowercase hexadecimal digits, indexed by their value.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Returns the 32 digits of the hyphenated representation, or `None` if the
+/// hyphens are misplaced.
+fn strip_hyphens(input: &[u8; 36]) -> Option<[u8; 32]> {
+    if [8, 13, 18, 23].iter().any(|&index| input[index] != b'-') {
+        return None;
+    }
+    let mut digits = [0; 32];
+    digits[..8].copy_from_slice(&input[..8]);
+    digits[8..12].copy_from_slice(&input[9..13]);
+    digits[12..16].copy_from_slice(&input[14..18]);
+    digits[16..20].copy_from_slice(&input[19..23]);
+    digits[20..].copy_from_slice(&input[24..]);
+    Some(digits)
+}
+
+/// Decodes the hyphenated representation, or returns `None` if it is invalid.
+pub(crate) fn decode_hyphenated(input: &[u8; 36]) -> Option<[u8; 16]> {
+    decode_simple(&strip_hyphens(input)?)
+}
+
+/// Decodes the simple representation, or returns `None` if it is invalid.
+#[allow(unreachable_code)]
+pub(crate) fn decode_simple(digits: &[u8; 32]) -> Option<[u8; 16]> {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        #[cfg(feature = "std")]
+        if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: the CPU supports AVX2.
+            return unsafe { x86_64::decode_avx2(digits) };
+        }
+        // SAFETY: SSE2 is enabled at compile time.
+        return unsafe { x86_64::decode_sse2(digits) };
+    }
+    // SAFETY: NEON is enabled at compile time.
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    return unsafe { aarch64::decode_neon(digits) };
+    scalar::decode(digits)
+}
+
+/// Encodes the hyphenated representation, in lowercase.
+pub(crate) fn encode_hyphenated(bytes: &[u8; 16], buffer: &mut [u8; 36]) {
+    let digits = encode_simple(bytes);
+    buffer[..8].copy_from_slice(&digits[..8]);
+    buffer[9..13].copy_from_slice(&digits[8..12]);
+    buffer[14..18].copy_from_slice(&digits[12..16]);
+    buffer[19..23].copy_from_slice(&digits[16..20]);
+    buffer[24..].copy_from_slice(&digits[20..]);
+    for index in [8, 13, 18, 23] {
+        buffer[index] = b'-';
+    }
+}
+
+/// Encodes the simple representation, in lowercase.
+#[allow(unreachable_code)]
+fn encode_simple(bytes: &[u8; 16]) -> [u8; 32] {
+    // SAFETY: SSE2 is enabled at compile time.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    return unsafe { x86_64::encode_sse2(bytes) };
+    // SAFETY: NEON is enabled at compile time.
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    return unsafe { aarch64::encode_neon(bytes) };
+    scalar::encode(bytes)
+}
+
+/// The portable implementation, used on the targets without vector
+/// instructions and as the reference of the tests.
+mod scalar {
+    use super::HEX_DIGITS;
+
+    /// Decodes the simple representation, or returns `None` if it is invalid.
+    #[cfg_attr(
+        any(
+            all(target_arch = "x86_64", target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon")
+        ),
+        allow(dead_code)
+    )]
+    pub(super) fn decode(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        let mut bytes = [0; 16];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+            *byte = crate::parse::hex_value(pair[0])? << 4 | crate::parse::hex_value(pair[1])?;
+        }
+        Some(bytes)
+    }
+
+    /// Encodes the simple representation, in lowercase.
+    #[cfg_attr(
+        any(
+            all(target_arch = "x86_64", target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon")
+        ),
+        allow(dead_code)
+    )]
+    pub(super) fn encode(bytes: &[u8; 16]) -> [u8; 32] {
+        let mut digits = [0; 32];
+        for (pair, byte) in digits.chunks_exact_mut(2).zip(bytes) {
+            pair[0] = HEX_DIGITS[usize::from(byte >> 4)];
+            pair[1] = HEX_DIGITS[usize::from(byte & 0x0F)];
+        }
+        digits
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+/// The SSE2 and AVX2 implementations.
+///
+/// Each vector of digits is validated and converted at once: `c - '0'` is at
+/// most 9 for the decimal digits only, and `(c | 0x20) - 'a'` is at most 5 for
+/// the letters only, in both cases.
+mod x86_64 {
+    use core::arch::x86_64::{
+        __m128i, _mm_add_epi8, _mm_and_si128, _mm_cmpeq_epi8, _mm_cmpgt_epi8, _mm_loadu_si128,
+        _mm_min_epu8, _mm_movemask_epi8, _mm_or_si128, _mm_packus_epi16, _mm_set1_epi8,
+        _mm_set1_epi16, _mm_slli_epi16, _mm_srli_epi16, _mm_storeu_si128, _mm_sub_epi8,
+        _mm_unpackhi_epi8, _mm_unpacklo_epi8,
+    };
+
+    /// Converts 16 hexadecimal digits to their values, and returns them with
+    /// the mask of the valid digits.
+    #[target_feature(enable = "sse2")]
+    fn hex_values(chars: __m128i) -> (__m128i, i32) {
+        let digits = _mm_sub_epi8(chars, _mm_set1_epi8(0x30));
+        let is_digit = _mm_cmpeq_epi8(_mm_min_epu8(digits, _mm_set1_epi8(9)), digits);
+        let letters = _mm_sub_epi8(
+            _mm_or_si128(chars, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(0x61),
+        );
+        let is_letter = _mm_cmpeq_epi8(_mm_min_epu8(letters, _mm_set1_epi8(5)), letters);
+        let values = _mm_or_si128(
+            _mm_and_si128(is_digit, digits),
+            _mm_and_si128(is_letter, _mm_add_epi8(letters, _mm_set1_epi8(10))),
+        );
+        (values, _mm_movemask_epi8(_mm_or_si128(is_digit, is_letter)))
+    }
+
+    /// Merges the pairs of nibbles into the low byte of each 16-bit lane.
+    #[target_feature(enable = "sse2")]
+    fn merge_nibbles(values: __m128i) -> __m128i {
+        let high = _mm_slli_epi16(_mm_and_si128(values, _mm_set1_epi16(0x00FF)), 4);
+        _mm_or_si128(high, _mm_srli_epi16(values, 8))
+    }
+
+    /// Decodes the simple representation with SSE2, or returns `None` if it
+    /// is invalid.
+    #[target_feature(enable = "sse2")]
+    pub(super) fn decode_sse2(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        // SAFETY: the two loads read the 32 bytes of the array.
+        let (first, second) = unsafe {
+            (
+                _mm_loadu_si128(digits.as_ptr().cast()),
+                _mm_loadu_si128(digits.as_ptr().add(16).cast()),
+            )
+        };
+        let (first, first_valid) = hex_values(first);
+        let (second, second_valid) = hex_values(second);
+        if first_valid & second_valid != 0xFFFF {
+            return None;
+        }
+        let packed = _mm_packus_epi16(merge_nibbles(first), merge_nibbles(second));
+        let mut bytes = [0; 16];
+        // SAFETY: the store writes the 16 bytes of the array.
+        unsafe { _mm_storeu_si128(bytes.as_mut_ptr().cast(), packed) };
+        Some(bytes)
+    }
+
+    /// Decodes the simple representation with AVX2, or returns `None` if it
+    /// is invalid.
+    ///
+    /// # Safety
+    ///
+    /// * The CPU must support AVX2.
+    #[cfg(feature = "std")]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn decode_avx2(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        use core::arch::x86_64::{
+            __m256i, _mm256_add_epi8, _mm256_and_si256, _mm256_castsi256_si128, _mm256_cmpeq_epi8,
+            _mm256_loadu_si256, _mm256_min_epu8, _mm256_movemask_epi8, _mm256_or_si256,
+            _mm256_packus_epi16, _mm256_permute4x64_epi64, _mm256_set1_epi8, _mm256_set1_epi16,
+            _mm256_slli_epi16, _mm256_srli_epi16, _mm256_sub_epi8,
+        };
+
+        // SAFETY: the load reads the 32 bytes of the array.
+        let chars: __m256i = unsafe { _mm256_loadu_si256(digits.as_ptr().cast()) };
+        let decimal = _mm256_sub_epi8(chars, _mm256_set1_epi8(0x30));
+        let is_digit = _mm256_cmpeq_epi8(_mm256_min_epu8(decimal, _mm256_set1_epi8(9)), decimal);
+        let letters = _mm256_sub_epi8(
+            _mm256_or_si256(chars, _mm256_set1_epi8(0x20)),
+            _mm256_set1_epi8(0x61),
+        );
+        let is_letter = _mm256_cmpeq_epi8(_mm256_min_epu8(letters, _mm256_set1_epi8(5)), letters);
+        if _mm256_movemask_epi8(_mm256_or_si256(is_digit, is_letter)) != -1 {
+            return None;
+        }
+        let values = _mm256_or_si256(
+            _mm256_and_si256(is_digit, decimal),
+            _mm256_and_si256(is_letter, _mm256_add_epi8(letters, _mm256_set1_epi8(10))),
+        );
+        let merged = _mm256_or_si256(
+            _mm256_slli_epi16(_mm256_and_si256(values, _mm256_set1_epi16(0x00FF)), 4),
+            _mm256_srli_epi16(values, 8),
+        );
+        // The packing works within each 128-bit half, so the bytes decoded
+        // from the two halves are gathered in the first 64-bit lanes.
+        let packed = _mm256_packus_epi16(merged, merged);
+        let ordered = _mm256_permute4x64_epi64::<0b1000>(packed);
+        let mut bytes = [0; 16];
+        // SAFETY: the store writes the 16 bytes of the array.
+        unsafe { _mm_storeu_si128(bytes.as_mut_ptr().cast(), _mm256_castsi256_si128(ordered)) };
+        Some(bytes)
+    }
+
+    /// Converts 16 nibbles to their lowercase hexadecimal digits.
+    #[target_feature(enable = "sse2")]
+    fn hex_digits(nibbles: __m128i) -> __m128i {
+        // The letters are 39 characters after the digit following '9'.
+        let letters = _mm_and_si128(_mm_cmpgt_epi8(nibbles, _mm_set1_epi8(9)), _mm_set1_epi8(39));
+        _mm_add_epi8(_mm_add_epi8(nibbles, _mm_set1_epi8(0x30)), letters)
+    }
+
+    /// Encodes the simple representation with SSE2, in lowercase.
+    #[target_feature(enable = "sse2")]
+    pub(super) fn encode_sse2(bytes: &[u8; 16]) -> [u8; 32] {
+        // SAFETY: the load reads the 16 bytes of the array.
+        let input = unsafe { _mm_loadu_si128(bytes.as_ptr().cast()) };
+        let low = _mm_and_si128(input, _mm_set1_epi8(0x0F));
+        let high = _mm_and_si128(_mm_srli_epi16(input, 4), _mm_set1_epi8(0x0F));
+        let mut digits = [0; 32];
+        // SAFETY: the two stores write the 32 bytes of the array.
+        unsafe {
+            _mm_storeu_si128(
+                digits.as_mut_ptr().cast(),
+                hex_digits(_mm_unpacklo_epi8(high, low)),
+            );
+            _mm_storeu_si128(
+                digits.as_mut_ptr().add(16).cast(),
+                hex_digits(_mm_unpackhi_epi8(high, low)),
+            );
+        }
+        digits
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+/// The NEON implementation, following the same approach as the SSE2 one.
+mod aarch64 {
+    use super::HEX_DIGITS;
+    use core::arch::aarch64::{
+        uint8x16_t, vaddq_u8, vandq_u8, vbslq_u8, vcleq_u8, vdupq_n_u8, vld1q_u8, vminvq_u8,
+        vorrq_u8, vqtbl1q_u8, vshlq_n_u8, vshrq_n_u8, vst1q_u8, vsubq_u8, vuzp1q_u8, vuzp2q_u8,
+        vzip1q_u8, vzip2q_u8,
+    };
+
+    /// Converts 16 hexadecimal digits to their values, and returns them with
+    /// the mask of the valid digits.
+    #[target_feature(enable = "neon")]
+    fn hex_values(chars: uint8x16_t) -> (uint8x16_t, uint8x16_t) {
+        let digits = vsubq_u8(chars, vdupq_n_u8(b'0'));
+        let is_digit = vcleq_u8(digits, vdupq_n_u8(9));
+        let letters = vsubq_u8(vorrq_u8(chars, vdupq_n_u8(0x20)), vdupq_n_u8(b'a'));
+        let is_letter = vcleq_u8(letters, vdupq_n_u8(5));
+        let values = vbslq_u8(is_digit, digits, vaddq_u8(letters, vdupq_n_u8(10)));
+        (values, vorrq_u8(is_digit, is_letter))
+    }
+
+    /// Decodes the simple representation with NEON, or returns `None` if it
+    /// is invalid.
+    #[target_feature(enable = "neon")]
+    pub(super) fn decode_neon(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        // SAFETY: the two loads read the 32 bytes of the array.
+        let (first, second) =
+            unsafe { (vld1q_u8(digits.as_ptr()), vld1q_u8(digits.as_ptr().add(16))) };
+        let (first, first_valid) = hex_values(first);
+        let (second, second_valid) = hex_values(second);
+        if vminvq_u8(vandq_u8(first_valid, second_valid)) != 0xFF {
+            return None;
+        }
+        let packed = vorrq_u8(
+            vshlq_n_u8::<4>(vuzp1q_u8(first, second)),
+            vuzp2q_u8(first, second),
+        );
+        let mut bytes = [0; 16];
+        // SAFETY: the store writes the 16 bytes of the array.
+        unsafe { vst1q_u8(bytes.as_mut_ptr(), packed) };
+        Some(bytes)
+    }
+
+    /// Encodes the simple representation with NEON, in lowercase.
+    #[target_feature(enable = "neon")]
+    pub(super) fn encode_neon(bytes: &[u8; 16]) -> [u8; 32] {
+        // SAFETY: the loads read the 16 bytes of the arrays.
+        let (input, table) = unsafe { (vld1q_u8(bytes.as_ptr()), vld1q_u8(HEX_DIGITS.as_ptr())) };
+        let high = vqtbl1q_u8(table, vshrq_n_u8::<4>(input));
+        let low = vqtbl1q_u8(table, vandq_u8(input, vdupq_n_u8(0x0F)));
+        let mut digits = [0; 32];
+        // SAFETY: the two stores write the 32 bytes of the array.
+        unsafe {
+            vst1q_u8(digits.as_mut_ptr(), vzip1q_u8(high, low));
+            vst1q_u8(digits.as_mut_ptr().add(16), vzip2q_u8(high, low));
+        }
+        digits
+    }
+}
+
+impl Uuid {
+    /// Parses the simple, hyphenated, braced or URN representation of a `Uuid`,
+    /// in either case, with the vector instructions of the CPU when available.
+    ///
+    /// It is equivalent to [`str::parse`], and reports the same errors.
+    ///
+    /// # Errors
+    ///
+    /// * If the input does not have the length of any accepted representation.
+    /// * If the input contains an unexpected character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, Uuid};
+    ///
+    /// let uuid = Uuid::parse("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+    /// assert_eq!(uuid.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse("67e55044-10b1-426f-9247-bb680e5fe0cZ"),
+    ///     Err(Error::InvalidCharacter { character: 'Z', index: 35 })
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        crate::parse::parse(input).map(Self::from)
+    }
+
+    /// Formats the `Uuid` in the lowercase hyphenated representation into the
+    /// provided buffer, with the vector instructions of the CPU when available,
+    /// and returns it as a string.
+    ///
+    /// The result is the same as the one of the [`Display`](core::fmt::Display)
+    /// implementation, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    /// let mut buffer = [0; 36];
+    /// assert_eq!(uuid.format_into(&mut buffer), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// ```
+    pub fn format_into<'buffer>(&self, buffer: &'buffer mut [u8; 36]) -> &'buffer str {
+        encode_hyphenated(self.as_bytes(), buffer);
+        // SAFETY: the buffer only contains hexadecimal digits and hyphens.
+        unsafe { core::str::from_utf8_unchecked(buffer) }
+    }
+
+    /// Parses each input into the `Uuid` at the same position of `uuids`, as
+    /// with [`Uuid::parse`].
+    ///
+    /// # Errors
+    ///
+    /// * If an input is not a valid representation of a `Uuid`, with the
+    ///   index of the first such input. The previous ones are already parsed.
+    ///
+    /// # Panics
+    ///
+    /// * If `inputs` and `uuids` do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, Uuid};
+    ///
+    /// let column = ["67e55044-10b1-426f-9247-bb680e5fe0c8", "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}"];
+    /// let mut uuids = [Uuid::default(); 2];
+    /// Uuid::parse_slice(&column, &mut uuids).unwrap();
+    /// assert_eq!(uuids[1].to_string(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_slice(&["67e55044-10b1-426f-9247-bb680e5fe0c8", "nope"], &mut uuids),
+    ///     Err((1, Error::InvalidLength { expected: &[32, 36, 38, 45], found: 4 }))
+    /// );
+    /// ```
+    pub fn parse_slice<S: AsRef<str>>(
+        inputs: &[S],
+        uuids: &mut [Uuid],
+    ) -> Result<(), (usize, Error)> {
+        assert_eq!(
+            inputs.len(),
+            uuids.len(),
+            "The inputs and the UUIDs must have the same length"
+        );
+        for (index, (input, uuid)) in inputs.iter().zip(uuids.iter_mut()).enumerate() {
+            *uuid = Self::parse(input.as_ref()).map_err(|error| (index, error))?;
+        }
+        Ok(())
+    }
+
+    /// Formats each `Uuid` into the buffer at the same position of `buffers`,
+    /// as with [`Uuid::format_into`].
+    ///
+    /// # Panics
+    ///
+    /// * If `uuids` and `buffers` do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuids = [Uuid::default(), Uuid::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()];
+    /// let mut buffers = [[0; 36]; 2];
+    /// Uuid::format_slice(&uuids, &mut buffers);
+    /// assert_eq!(&buffers[1], b"67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// ```
+    pub fn format_slice(uuids: &[Uuid], buffers: &mut [[u8; 36]]) {
+        assert_eq!(
+            uuids.len(),
+            buffers.len(),
+            "The UUIDs and the buffers must have the same length"
+        );
+        for (uuid, buffer) in uuids.iter().zip(buffers.iter_mut()) {
+            encode_hyphenated(uuid.as_bytes(), buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    /// Returns 32 digits decoding to the provided bytes, mixing both cases.
+    fn mixed_case_digits(bytes: &[u8; 16]) -> [u8; 32] {
+        let mut digits = scalar::encode(bytes);
+        for digit in digits.iter_mut().step_by(3) {
+            digit.make_ascii_uppercase();
+        }
+        digits
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_matches_uuid_crate() {
+        for _ in 0..1_000 {
+            let uuid = uuid::Uuid::new_v4();
+            let mut buffer = [0; 36];
+            encode_hyphenated(uuid.as_bytes(), &mut buffer);
+            assert_eq!(&buffer, uuid.hyphenated().to_string().as_bytes());
+            assert_eq!(decode_hyphenated(&buffer), Some(*uuid.as_bytes()));
+            assert_eq!(
+                decode_simple(&mixed_case_digits(uuid.as_bytes())),
+                Some(*uuid.as_bytes())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_every_byte_value() {
+        let reference = mixed_case_digits(uuid::Uuid::new_v4().as_bytes());
+        for position in 0..32 {
+            for byte in 0..=u8::MAX {
+                let mut digits = reference;
+                digits[position] = byte;
+                assert_eq!(decode_simple(&digits), scalar::decode(&digits), "{byte:#x}");
+            }
+        }
+        for value in 0..=u8::MAX {
+            let bytes = [value; 16];
+            assert_eq!(encode_simple(&bytes), scalar::encode(&bytes));
+        }
+    }
+
+    #[test]
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    fn test_avx2_matches_sse2() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let reference = mixed_case_digits(uuid::Uuid::new_v4().as_bytes());
+        for position in 0..32 {
+            for byte in 0..=u8::MAX {
+                let mut digits = reference;
+                digits[position] = byte;
+                // SAFETY: the CPU supports AVX2.
+                let avx2 = unsafe { x86_64::decode_avx2(&digits) };
+                // SAFETY: SSE2 is enabled at compile time.
+                let sse2 = unsafe { x86_64::decode_sse2(&digits) };
+                assert_eq!(avx2, sse2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_misplaced_hyphens() {
+        let mut buffer = [0; 36];
+        encode_hyphenated(&[0xAB; 16], &mut buffer);
+        buffer.swap(8, 9);
+        assert_eq!(decode_hyphenated(&buffer), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_batch() {
+        let uuids: Vec<Uuid> = (0..100).map(|_| Uuid::new_v4()).collect();
+        let mut buffers = vec![[0; 36]; uuids.len()];
+        Uuid::format_slice(&uuids, &mut buffers);
+        let inputs: Vec<&str> = buffers
+            .iter()
+            .map(|buffer| core::str::from_utf8(buffer).unwrap())
+            .collect();
+        for (uuid, input) in uuids.iter().zip(&inputs) {
+            assert_eq!(uuid.to_string(), *input);
+        }
+
+        let mut parsed = vec![Uuid::default(); uuids.len()];
+        Uuid::parse_slice(&inputs, &mut parsed).unwrap();
+        assert_eq!(parsed, uuids);
+    }
+}
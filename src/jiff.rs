@@ -0,0 +1,66 @@
+#![cfg(feature = "jiff")]
+//! Submodule providing conversions between the timestamps embedded in
+//! time-based UUIDs and [`jiff`] types.
+
+use ::jiff::Timestamp;
+
+use crate::{FixedClock, UnixTimestamp};
+
+impl UnixTimestamp for Timestamp {
+    fn unix_timestamp_nanos(&self) -> i128 {
+        self.as_nanosecond()
+    }
+
+    fn from_unix_timestamp_nanos(unix_timestamp_nanos: i128) -> Option<Self> {
+        Timestamp::from_nanosecond(unix_timestamp_nanos).ok()
+    }
+}
+
+impl From<Timestamp> for FixedClock {
+    fn from(timestamp: Timestamp) -> Self {
+        Self::new(timestamp.as_nanosecond())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uuid;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_round_trip() {
+        let created_at = Timestamp::from_second(1_700_000_000).unwrap();
+        let uuid = Uuid::v7_from(created_at).unwrap();
+        assert_eq!(uuid.created_at_as::<Timestamp>(), Some(created_at));
+        assert!(Uuid::v7_lower_bound(created_at) <= uuid);
+        assert!(uuid <= Uuid::v7_upper_bound(created_at));
+        assert_eq!(
+            Uuid::v7_upper_bound(created_at).to_string(),
+            "018bcfe5-6800-7fff-bfff-ffffffffffff"
+        );
+        assert_eq!(Uuid::new_v4().created_at_as::<Timestamp>(), None);
+    }
+
+    #[test]
+    fn test_v7_from_random_bytes() {
+        let created_at = Timestamp::from_second(1_700_000_000).unwrap();
+        let uuid = Uuid::v7_from_random_bytes(created_at, [0xFF; 10]).unwrap();
+        assert_eq!(uuid.created_at_as::<Timestamp>(), Some(created_at));
+        assert_eq!(uuid, Uuid::v7_upper_bound(created_at));
+        assert_eq!(
+            Uuid::v7_from_random_bytes(Timestamp::from_second(-1).unwrap(), [0; 10]),
+            Err(crate::Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1_000_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        let timestamp = Timestamp::from_nanosecond(-1).unwrap();
+        assert_eq!(timestamp.unix_timestamp_nanos(), -1);
+        assert_eq!(Timestamp::from_unix_timestamp_nanos(i128::MAX), None);
+        assert_eq!(FixedClock::from(timestamp), FixedClock::new(-1));
+    }
+}
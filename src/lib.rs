@@ -1,9 +1,54 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+extern crate alloc;
 
 use core::str::FromStr;
 
+mod batch;
+mod chrono;
+mod clock;
 pub mod diesel_impls;
+pub mod encoding;
+mod error;
+mod generator;
+mod gregorian;
+mod hex;
+mod jiff;
+mod monotonic;
+mod namespace;
+mod parse;
 mod redis;
+mod seeded;
+pub mod serde;
+pub mod sql_functions;
+pub mod sqlite;
+mod time;
+mod timestamp;
+mod typeid;
+mod ulid;
+mod v8;
+mod versioned;
+
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock};
+pub use encoding::{Base58, Base62, Base64Url, CrockfordBase32};
+pub use error::Error;
+pub use generator::{FnGenerator, UuidGenerator};
+#[cfg(feature = "std")]
+pub use generator::{SequenceGenerator, UtcV7Generator, V4Generator};
+pub use gregorian::NodeId;
+#[cfg(feature = "std")]
+pub use monotonic::V7Generator;
+pub use namespace::Namespace;
+#[cfg(feature = "deterministic")]
+pub use seeded::{SeededGenerator, SeededV7Generator};
+pub use timestamp::UnixTimestamp;
+pub use typeid::{Prefix, PrefixedUuid};
+pub use v8::{V8Builder, V8Field, V8Fields, V8Layout};
+pub use versioned::{UuidV4, UuidV7};
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11,8 +56,10 @@ mod redis;
     feature = "diesel",
     derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "diesel", diesel(sql_type = crate::diesel_impls::Uuid))]
+#[cfg_attr(feature = "postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "sqlite", diesel(sql_type = crate::diesel_impls::LenientUuid))]
 /// A wrapper around the `uuid` crate's `Uuid` type.
 ///
 /// # Examples
@@ -29,6 +76,7 @@ pub struct Uuid(uuid::Uuid);
 
 impl Uuid {
     #[must_use]
+    #[cfg(feature = "std")]
     /// Creates a new `Uuid` using the `uuid` crate's `new_v4` method.
     ///
     /// # Examples
@@ -43,7 +91,33 @@ impl Uuid {
     }
 
     #[must_use]
-    /// Creates a new `Uuid` using the `uuid` crate's `new_v7` method with the current UTC timestamp.
+    /// Creates a version 4 `Uuid` from caller-provided random bytes.
+    ///
+    /// The version and variant bits are overwritten, so the remaining 122 bits
+    /// are as random as the provided bytes. This is the constructor to use
+    /// without the `std` feature, with the entropy of the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::v4_from_random_bytes([0xFF; 16]);
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::Random));
+    /// assert_eq!(uuid.to_string(), "ffffffff-ffff-4fff-bfff-ffffffffffff");
+    /// ```
+    pub const fn v4_from_random_bytes(random_bytes: [u8; 16]) -> Self {
+        Self(uuid::Builder::from_random_bytes(random_bytes).into_uuid())
+    }
+
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Creates a new version 7 `Uuid` with the current UTC timestamp, read from the
+    /// [`SystemClock`], and random bits built into a UUID with [`uuid::Builder`].
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time before the Unix epoch, see [`Uuid::try_utc_v7`].
     ///
     /// # Examples
     ///
@@ -53,28 +127,263 @@ impl Uuid {
     /// let uuid = Uuid::utc_v7();
     /// ```
     pub fn utc_v7() -> Self {
-        let utc_now = ::chrono::Utc::now();
-        ::uuid::Uuid::new_v7(::uuid::Timestamp::from_unix_time(
-            u64::try_from(utc_now.timestamp()).expect("Time went backwards"),
-            utc_now.timestamp_subsec_nanos(),
-            0,
-            12,
-        ))
-        .into()
+        Self::try_utc_v7().expect("Time went backwards")
+    }
+
+    #[cfg(feature = "std")]
+    /// Creates a new version 7 `Uuid` with the current UTC timestamp.
+    ///
+    /// # Errors
+    ///
+    /// * If the system clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let uuid = Uuid::try_utc_v7().unwrap();
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+    /// ```
+    pub fn try_utc_v7() -> Result<Self, Error> {
+        Self::try_v7_with_clock(&SystemClock)
+    }
+
+    #[cfg(feature = "std")]
+    /// Creates a new version 7 `Uuid` with the time reported by the provided [`Clock`].
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, Uuid};
+    ///
+    /// let clock = FixedClock::new(1_700_000_000_000_000_000);
+    /// let uuid = Uuid::try_v7_with_clock(&clock).unwrap();
+    /// assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (1_700_000_000, 0));
+    /// ```
+    pub fn try_v7_with_clock<C: Clock + ?Sized>(clock: &C) -> Result<Self, Error> {
+        let mut random_bytes = [0; 10];
+        random_bytes.copy_from_slice(&uuid::Uuid::new_v4().as_bytes()[6..]);
+        Self::try_v7_from_random_bytes(clock, random_bytes)
+    }
+
+    /// Creates a new version 7 `Uuid` with the time reported by the provided
+    /// [`Clock`] and caller-provided random bytes.
+    ///
+    /// The version and variant bits are overwritten, so 74 of the 80 provided
+    /// bits are kept. This is the constructor to use without the `std`
+    /// feature, with the clock and the entropy of the platform.
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, Uuid};
+    ///
+    /// let clock = FixedClock::new(1_700_000_000_000_000_000);
+    /// let uuid = Uuid::try_v7_from_random_bytes(&clock, [0; 10]).unwrap();
+    /// assert_eq!(uuid.to_string(), "018bcfe5-6800-7000-8000-000000000000");
+    /// ```
+    pub fn try_v7_from_random_bytes<C: Clock + ?Sized>(
+        clock: &C,
+        random_bytes: [u8; 10],
+    ) -> Result<Self, Error> {
+        Ok(
+            uuid::Builder::from_unix_timestamp_millis(
+                clock.unix_timestamp_millis()?,
+                &random_bytes,
+            )
+            .into_uuid()
+            .into(),
+        )
+    }
+
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Creates a new `Uuid` using the process-wide [`V7Generator`], which guarantees that
+    /// UUIDs created within the same millisecond are still strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// let first = Uuid::monotonic_v7();
+    /// let second = Uuid::monotonic_v7();
+    /// assert!(first < second);
+    /// ```
+    pub fn monotonic_v7() -> Self {
+        V7Generator::global().generate()
+    }
+
+    /// Creates a `Uuid` from its 16 bytes, in big-endian order.
+    ///
+    /// # Errors
+    ///
+    /// * If the slice is not exactly 16 bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, Uuid};
+    ///
+    /// let uuid = Uuid::new_v4();
+    /// assert_eq!(Uuid::from_slice(uuid.as_bytes()), Ok(uuid));
+    /// assert_eq!(
+    ///     Uuid::from_slice(&[0; 17]),
+    ///     Err(Error::InvalidByteLength { found: 17 })
+    /// );
+    /// ```
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        <[u8; 16]>::try_from(bytes)
+            .map(Self::from)
+            .map_err(|_| Error::InvalidByteLength { found: bytes.len() })
+    }
+
+    #[must_use]
+    /// Creates a `Uuid` from its 16 bytes, in big-endian order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// const SENTINEL: Uuid = Uuid::from_bytes([
+    ///     0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+    /// ]);
+    /// assert_eq!(SENTINEL.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(uuid::Uuid::from_bytes(bytes))
+    }
+
+    #[must_use]
+    /// Creates a `Uuid` from its 128 bits, the most significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// const SENTINEL: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+    /// assert_eq!(SENTINEL.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// ```
+    pub const fn from_u128(value: u128) -> Self {
+        Self(uuid::Uuid::from_u128(value))
+    }
+
+    #[must_use]
+    /// Creates a `Uuid` from its most and least significant 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// const SENTINEL: Uuid = Uuid::from_u64_pair(0x67e5_5044_10b1_426f, 0x9247_bb68_0e5f_e0c8);
+    /// assert_eq!(SENTINEL, Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+    /// ```
+    pub const fn from_u64_pair(high_bits: u64, low_bits: u64) -> Self {
+        Self(uuid::Uuid::from_u64_pair(high_bits, low_bits))
+    }
+
+    #[must_use]
+    /// Returns the nil `Uuid`, with all its bits set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// const NIL: Uuid = Uuid::nil();
+    /// assert_eq!(NIL.to_string(), "00000000-0000-0000-0000-000000000000");
+    /// assert_eq!(NIL, Uuid::default());
+    /// ```
+    pub const fn nil() -> Self {
+        Self(uuid::Uuid::nil())
+    }
+
+    #[must_use]
+    /// Returns the max `Uuid`, with all its bits set to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    ///
+    /// const MAX: Uuid = Uuid::max();
+    /// assert_eq!(MAX.to_string(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+    /// ```
+    pub const fn max() -> Self {
+        Self(uuid::Uuid::max())
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    /// Parses a textual representation at compile time, for the [`uuid!`] macro.
+    ///
+    /// # Panics
+    ///
+    /// * If the input is not a valid textual representation of a `Uuid`.
+    pub const fn parse_literal(input: &str) -> Self {
+        match uuid::Uuid::try_parse(input) {
+            Ok(uuid) => Self(uuid),
+            Err(_) => panic!("invalid UUID literal"),
+        }
     }
 }
 
+#[macro_export]
+/// Creates a `const` [`Uuid`] from its simple, hyphenated, braced or URN
+/// representation, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Uuid, uuid};
+///
+/// const SENTINEL: Uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert_eq!(SENTINEL, Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+/// assert_eq!(uuid!("{67E55044-10B1-426F-9247-BB680E5FE0C8}"), SENTINEL);
+/// ```
+///
+/// An invalid literal does not compile:
+///
+/// ```compile_fail
+/// const BROKEN: rosetta_uuid::Uuid = rosetta_uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0cZ");
+/// ```
+macro_rules! uuid {
+    ($uuid:expr) => {
+        const { $crate::Uuid::parse_literal($uuid) }
+    };
+}
+
 impl Default for Uuid {
     fn default() -> Self {
-        Self(uuid::Uuid::nil())
+        Self::nil()
     }
 }
 
 impl FromStr for Uuid {
-    type Err = uuid::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(uuid::Uuid::from_str(s)?))
+        Self::parse(s)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Uuid {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(bytes)
     }
 }
 
@@ -141,17 +450,17 @@ impl core::ops::DerefMut for Uuid {
 }
 
 impl core::fmt::Display for Uuid {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.format_into(&mut [0; 36]))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::cmp::Ordering;
-    use std::collections::HashSet;
-    use std::str::FromStr;
+    use alloc::format;
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     #[test]
     fn test_default() {
@@ -160,6 +469,25 @@ mod tests {
         assert_eq!(uuid.to_string(), "00000000-0000-0000-0000-000000000000");
     }
 
+    #[test]
+    fn test_const_constructors() {
+        const LITERAL: Uuid = uuid!("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
+        const FROM_BYTES: Uuid = Uuid::from_bytes([
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ]);
+        const FROM_U128: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+        const FROM_U64_PAIR: Uuid =
+            Uuid::from_u64_pair(0x67e5_5044_10b1_426f, 0x9247_bb68_0e5f_e0c8);
+        assert_eq!(LITERAL, FROM_BYTES);
+        assert_eq!(LITERAL, FROM_U128);
+        assert_eq!(LITERAL, FROM_U64_PAIR);
+        assert_eq!(uuid!("67e5504410b1426f9247bb680e5fe0c8"), LITERAL);
+        assert_eq!(Uuid::nil().0, uuid::Uuid::nil());
+        assert_eq!(Uuid::max().0, uuid::Uuid::max());
+        assert!(Uuid::nil() < Uuid::max());
+    }
+
     #[test]
     fn test_from_str() {
         let s = "67e55044-10b1-426f-9247-bb680e5fe0c8";
@@ -171,6 +499,19 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
+    fn test_from_slice() {
+        let uuid = Uuid::new_v4();
+        assert_eq!(Uuid::from_slice(uuid.as_bytes()), Ok(uuid));
+        assert_eq!(Uuid::try_from(uuid.as_bytes().as_slice()), Ok(uuid));
+        assert_eq!(
+            Uuid::from_slice(&[0; 15]),
+            Err(Error::InvalidByteLength { found: 15 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn test_from_conversions() {
         let inner = uuid::Uuid::new_v4();
         let wrapper: Uuid = inner.into();
@@ -192,6 +533,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_as_ref_as_mut() {
         let mut uuid = Uuid::new_v4();
 
@@ -208,6 +550,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_deref_deref_mut() {
         let mut uuid = Uuid::new_v4();
 
@@ -227,6 +570,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_standard_traits() {
         let uuid1 = Uuid::new_v4();
         let uuid2 = Clone::clone(&uuid1); // Clone
@@ -236,21 +580,23 @@ mod tests {
         assert_eq!(uuid1, uuid3); // PartialEq
         assert!(uuid1 == uuid2); // Eq check implicitly
 
-        let mut set = HashSet::new();
+        let mut set = std::collections::HashSet::new();
         set.insert(uuid1); // Hash
         assert!(set.contains(&uuid2));
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_ord() {
         let u1 = Uuid::default(); // nil, all zeros
         let u2 = Uuid::new_v4(); // random
 
-        assert_eq!(u1.cmp(&u2), Ordering::Less); // Ord
+        assert_eq!(u1.cmp(&u2), core::cmp::Ordering::Less); // Ord
         assert!(u1 < u2); // PartialOrd
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_new_constructors() {
         let v4 = Uuid::new_v4();
         assert_eq!(v4.get_version(), Some(uuid::Version::Random));
@@ -259,5 +605,52 @@ mod tests {
         // Version v7 might check differently depending on uuid crate version
         // assert_eq!(v7.get_version(), Some(uuid::Version::Sortable));
         assert!(!v7.is_nil());
+
+        let monotonic = Uuid::monotonic_v7();
+        assert_eq!(monotonic.get_version(), Some(uuid::Version::SortRand));
+
+        let fallible = Uuid::try_utc_v7().unwrap();
+        assert_eq!(fallible.get_version(), Some(uuid::Version::SortRand));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_with_clock() {
+        let clock = FixedClock::new(1_700_000_000_123_456_789);
+        let first = Uuid::try_v7_with_clock(&clock).unwrap();
+        let second = Uuid::try_v7_with_clock(&clock).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first.get_version(), Some(uuid::Version::SortRand));
+        assert_eq!(
+            first.get_timestamp().unwrap().to_unix(),
+            (1_700_000_000, 123_000_000)
+        );
+        assert_eq!(first.as_bytes()[..6], second.as_bytes()[..6]);
+
+        assert_eq!(
+            Uuid::try_v7_with_clock(&FixedClock::new(-1)),
+            Err(Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_random_bytes() {
+        let v4 = Uuid::v4_from_random_bytes([0; 16]);
+        assert_eq!(v4.get_version(), Some(uuid::Version::Random));
+        assert_eq!(v4.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(v4.to_string(), "00000000-0000-4000-8000-000000000000");
+
+        let clock = FixedClock::new(1_700_000_000_123_456_789);
+        let v7 = Uuid::try_v7_from_random_bytes(&clock, [0xFF; 10]).unwrap();
+        assert_eq!(v7.to_string(), "018bcfe5-687b-7fff-bfff-ffffffffffff");
+        assert_eq!(Uuid::try_v7_from_random_bytes(&clock, [0xFF; 10]), Ok(v7));
+        assert_eq!(
+            Uuid::try_v7_from_random_bytes(&FixedClock::new(-1), [0; 10]),
+            Err(Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1
+            })
+        );
     }
 }
@@ -0,0 +1,446 @@
+#![cfg(feature = "std")]
+//! Monotonic generation of version 7 UUIDs.
+//!
+//! [`Uuid::utc_v7`](crate::Uuid::utc_v7) leaves the sub-millisecond part of
+//! the identifier random, so two UUIDs created within the same millisecond
+//! sort in an arbitrary order. The [`V7Generator`] follows the *fixed
+//! bit-length dedicated counter* method (Method 1) of
+//! [RFC 9562, section 6.2](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
+//! and uses the 12 bits of `rand_a` as a counter, so that every UUID it
+//! produces is strictly greater than the previous one.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use rand_chacha::rand_core::RngCore;
+
+use crate::batch::seeded_rng;
+use crate::{Clock, Error, SystemClock, Uuid};
+
+/// Number of bits of the `rand_a` field used as the sub-millisecond counter.
+const COUNTER_BITS: u32 = 12;
+
+/// The largest value of the counter, after which it carries into the timestamp.
+const COUNTER_MAX: u64 = (1 << COUNTER_BITS) - 1;
+
+/// A thread-safe generator of strictly increasing version 7 UUIDs.
+///
+/// The counter is reset to zero whenever the millisecond changes and is
+/// incremented for each UUID created within the same millisecond. When the
+/// system clock moves backwards, the generator keeps the last timestamp and
+/// increments the counter, until the clock catches up. When the counter
+/// overflows, it carries into the timestamp, which is advanced by one
+/// millisecond.
+///
+/// The time is read from the [`SystemClock`] by default, and from any other
+/// [`Clock`] when the generator is built with [`V7Generator::with_clock`].
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::V7Generator;
+///
+/// let generator = V7Generator::new();
+/// let first = generator.generate();
+/// let second = generator.generate();
+/// assert!(first < second);
+///
+/// // A process-wide instance is available as well.
+/// let shared = V7Generator::global().generate();
+/// assert!(!shared.is_nil());
+/// ```
+#[derive(Debug, Default)]
+pub struct V7Generator<C = SystemClock> {
+    /// The last emitted state, packed as the Unix timestamp in milliseconds
+    /// shifted left by [`COUNTER_BITS`], plus the counter.
+    state: AtomicU64,
+    /// The source of time of the generator.
+    clock: C,
+}
+
+/// The process-wide generator returned by [`V7Generator::global`].
+static GLOBAL: V7Generator = V7Generator::new();
+
+impl V7Generator {
+    #[must_use]
+    /// Creates a new generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::V7Generator;
+    ///
+    /// let generator = V7Generator::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+
+    #[must_use]
+    /// Returns the process-wide generator.
+    ///
+    /// Using the same instance everywhere guarantees that all the UUIDs
+    /// created within the process are strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::V7Generator;
+    ///
+    /// let first = V7Generator::global().generate();
+    /// let second = V7Generator::global().generate();
+    /// assert!(first < second);
+    /// ```
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+}
+
+impl<C: Clock> V7Generator<C> {
+    #[must_use]
+    /// Creates a new generator reading the time from the provided [`Clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, V7Generator};
+    ///
+    /// let generator = V7Generator::with_clock(FixedClock::new(1_700_000_000_000_000_000));
+    /// let first = generator.generate();
+    /// let second = generator.generate();
+    /// assert!(first < second);
+    /// assert_eq!(first.get_timestamp(), second.get_timestamp());
+    /// ```
+    pub const fn with_clock(clock: C) -> Self {
+        Self {
+            state: AtomicU64::new(0),
+            clock,
+        }
+    }
+
+    #[must_use]
+    /// Creates a new version 7 `Uuid` greater than any previously created by this generator.
+    ///
+    /// # Panics
+    ///
+    /// * If the clock reports a time before the Unix epoch, see [`V7Generator::try_generate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::V7Generator;
+    ///
+    /// let generator = V7Generator::new();
+    /// let uuid = generator.generate();
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+    /// ```
+    pub fn generate(&self) -> Uuid {
+        self.try_generate().expect("Time went backwards")
+    }
+
+    /// Creates a new version 7 `Uuid` greater than any previously created by this generator.
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, FixedClock, V7Generator};
+    ///
+    /// let generator = V7Generator::with_clock(FixedClock::new(-1));
+    /// assert_eq!(
+    ///     generator.try_generate(),
+    ///     Err(Error::TimeBeforeUnixEpoch { unix_timestamp_nanos: -1 })
+    /// );
+    /// ```
+    pub fn try_generate(&self) -> Result<Uuid, Error> {
+        Ok(self.generate_at(self.clock.unix_timestamp_millis()?))
+    }
+
+    #[cfg(feature = "deterministic")]
+    /// Creates a new version 7 `Uuid` greater than any previously created by
+    /// this generator, with the provided random bytes in the `rand_b` field.
+    pub(crate) fn try_generate_with(&self, random_bytes: [u8; 8]) -> Result<Uuid, Error> {
+        Ok(self.generate_at_with(self.clock.unix_timestamp_millis()?, random_bytes))
+    }
+
+    /// Creates as many version 7 `Uuid`s as the slice holds, strictly increasing
+    /// and greater than any previously created by this generator.
+    ///
+    /// The clock is read again whenever the counter is exhausted, so that the
+    /// timestamps only run ahead of the clock when more than 4096 UUIDs, the
+    /// most that can share a millisecond, are created within a millisecond.
+    /// The random bits are drawn from a CSPRNG seeded once for the whole
+    /// batch. See [`Uuid::fill_v7`].
+    ///
+    /// # Panics
+    ///
+    /// * If the clock reports a time before the Unix epoch, see [`V7Generator::try_fill`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Uuid, V7Generator};
+    ///
+    /// let generator = V7Generator::new();
+    /// let mut uuids = [Uuid::default(); 10_000];
+    /// generator.fill(&mut uuids);
+    /// assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn fill(&self, uuids: &mut [Uuid]) {
+        self.try_fill(uuids).expect("Time went backwards");
+    }
+
+    /// Creates as many version 7 `Uuid`s as the slice holds, strictly increasing
+    /// and greater than any previously created by this generator.
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Unix epoch. The slice may then
+    ///   be partially filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, FixedClock, Uuid, V7Generator};
+    ///
+    /// let generator = V7Generator::with_clock(FixedClock::new(-1));
+    /// assert_eq!(
+    ///     generator.try_fill(&mut [Uuid::default(); 2]),
+    ///     Err(Error::TimeBeforeUnixEpoch { unix_timestamp_nanos: -1 })
+    /// );
+    /// ```
+    pub fn try_fill(&self, uuids: &mut [Uuid]) -> Result<(), Error> {
+        let mut rng = seeded_rng();
+        let mut last_reading = None;
+        for uuid in uuids {
+            let unix_millis = match last_reading {
+                Some(unix_millis) => unix_millis,
+                None => self.clock.unix_timestamp_millis()?,
+            };
+            let state = self.next_state(unix_millis);
+            *uuid = build(state, rng.next_u64().to_be_bytes());
+            // The next UUID would carry into the timestamp, which must then
+            // follow the clock if it has moved on.
+            last_reading = (state & COUNTER_MAX != COUNTER_MAX).then_some(unix_millis);
+        }
+        Ok(())
+    }
+
+    /// Returns an endless iterator of strictly increasing version 7 `Uuid`s,
+    /// drawing the random bits from a CSPRNG seeded once when the iterator is
+    /// created.
+    ///
+    /// Unlike [`V7Generator::fill`], the clock is read whenever a `Uuid` is
+    /// pulled, so that its timestamp is the time at which it was pulled even
+    /// when the iterator is consumed slowly.
+    ///
+    /// # Panics
+    ///
+    /// * If the clock reports a time before the Unix epoch while iterating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Uuid, V7Generator};
+    ///
+    /// let generator = V7Generator::new();
+    /// let uuids: Vec<Uuid> = generator.iter().take(10_000).collect();
+    /// assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Uuid> + '_ {
+        let mut rng = seeded_rng();
+        core::iter::repeat_with(move || {
+            let unix_millis = self
+                .clock
+                .unix_timestamp_millis()
+                .expect("Time went backwards");
+            self.generate_at_with(unix_millis, rng.next_u64().to_be_bytes())
+        })
+    }
+
+    /// Creates a new version 7 `Uuid` for the provided Unix timestamp in milliseconds.
+    fn generate_at(&self, unix_millis: u64) -> Uuid {
+        let mut random_bytes = [0; 8];
+        random_bytes.copy_from_slice(&uuid::Uuid::new_v4().as_bytes()[8..]);
+        self.generate_at_with(unix_millis, random_bytes)
+    }
+
+    /// Creates a new version 7 `Uuid` for the provided Unix timestamp in
+    /// milliseconds, with the provided random bytes in the `rand_b` field.
+    fn generate_at_with(&self, unix_millis: u64, random_bytes: [u8; 8]) -> Uuid {
+        build(self.next_state(unix_millis), random_bytes)
+    }
+
+    /// Advances the state of the generator for the provided Unix timestamp in
+    /// milliseconds, and returns the new state.
+    fn next_state(&self, unix_millis: u64) -> u64 {
+        let candidate = unix_millis << COUNTER_BITS;
+        let next = |previous: u64| candidate.max(previous + 1);
+        let previous = self
+            .state
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |previous| {
+                Some(next(previous))
+            })
+            .unwrap_or_else(|previous| previous);
+        next(previous)
+    }
+}
+
+/// Creates the version 7 `Uuid` of the provided packed state, with the provided
+/// random bytes in the `rand_b` field.
+fn build(state: u64, random_bytes: [u8; 8]) -> Uuid {
+    let mut counter_random_bytes = [0; 10];
+    // The builder only keeps the lower 12 bits of the first two bytes.
+    counter_random_bytes[..2].copy_from_slice(&state.to_be_bytes()[6..]);
+    counter_random_bytes[2..].copy_from_slice(&random_bytes);
+
+    uuid::Builder::from_unix_timestamp_millis(state >> COUNTER_BITS, &counter_random_bytes)
+        .into_uuid()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn unix_millis(uuid: Uuid) -> u64 {
+        let (seconds, nanos) = uuid.get_timestamp().unwrap().to_unix();
+        seconds * 1000 + u64::from(nanos) / 1_000_000
+    }
+
+    #[test]
+    fn test_strictly_increasing() {
+        let generator = V7Generator::new();
+        let mut previous = generator.generate();
+        for _ in 0..10_000 {
+            let next = generator.generate();
+            assert_eq!(next.get_version(), Some(uuid::Version::SortRand));
+            assert_eq!(next.get_variant(), uuid::Variant::RFC4122);
+            assert!(previous < next);
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn test_same_millisecond_uses_counter() {
+        let generator = V7Generator::new();
+        let first = generator.generate_at(1_700_000_000_000);
+        let second = generator.generate_at(1_700_000_000_000);
+        assert!(first < second);
+        assert_eq!(unix_millis(first), 1_700_000_000_000);
+        assert_eq!(unix_millis(second), 1_700_000_000_000);
+        assert_eq!(first.as_bytes()[6] & 0x0F, 0);
+        assert_eq!(first.as_bytes()[7], 0);
+        assert_eq!(second.as_bytes()[7], 1);
+    }
+
+    #[test]
+    fn test_counter_overflow_advances_timestamp() {
+        let generator = V7Generator::new();
+        let mut previous = generator.generate_at(1_700_000_000_000);
+        for _ in 0..(1 << COUNTER_BITS) {
+            let next = generator.generate_at(1_700_000_000_000);
+            assert!(previous < next);
+            previous = next;
+        }
+        assert_eq!(unix_millis(previous), 1_700_000_000_001);
+
+        // Once the clock catches up, the counter starts again from zero.
+        let caught_up = generator.generate_at(1_700_000_000_002);
+        assert!(previous < caught_up);
+        assert_eq!(caught_up.as_bytes()[7], 0);
+    }
+
+    #[test]
+    fn test_clock_going_backwards() {
+        let generator = V7Generator::new();
+        let first = generator.generate_at(1_700_000_000_000);
+        let second = generator.generate_at(1_600_000_000_000);
+        assert!(first < second);
+        assert_eq!(unix_millis(second), 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_iter_reads_clock_for_each_uuid() {
+        let now = core::cell::Cell::new(1_700_000_000_000_000_000_i128);
+        let clock = || now.get();
+        let generator = V7Generator::with_clock(clock);
+        let mut uuids = generator.iter();
+
+        let first = uuids.next().unwrap();
+        // The iterator is left aside for an hour before the next UUID is pulled.
+        now.set(now.get() + 3_600 * 1_000_000_000);
+        let second = uuids.next().unwrap();
+        assert!(first < second);
+        assert_eq!(unix_millis(first), 1_700_000_000_000);
+        assert_eq!(unix_millis(second), 1_700_003_600_000);
+    }
+
+    #[test]
+    fn test_fill_only_runs_ahead_when_counter_is_exhausted() {
+        let generator = V7Generator::with_clock(crate::FixedClock::new(1_700_000_000_000_000_000));
+        let mut uuids = vec![Uuid::nil(); 10_000];
+        generator.fill(&mut uuids);
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+        // 10,000 UUIDs exhaust the counter of two milliseconds, and no more.
+        assert_eq!(unix_millis(uuids[4_095]), 1_700_000_000_000);
+        assert_eq!(unix_millis(uuids[4_096]), 1_700_000_000_001);
+        assert_eq!(unix_millis(uuids[9_999]), 1_700_000_000_002);
+        assert_eq!(unix_millis(generator.generate()), 1_700_000_000_002);
+    }
+
+    #[test]
+    fn test_fill_reads_clock_when_counter_carries() {
+        let reads = core::cell::Cell::new(0);
+        // Every reading of the clock is a millisecond later than the previous one.
+        let clock = || {
+            reads.set(reads.get() + 1);
+            1_700_000_000_000_000_000 + i128::from(reads.get()) * 1_000_000
+        };
+        let generator = V7Generator::with_clock(clock);
+        let mut uuids = vec![Uuid::nil(); 10_000];
+        generator.fill(&mut uuids);
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(reads.get(), 3);
+        assert_eq!(unix_millis(uuids[0]), 1_700_000_000_001);
+        assert_eq!(unix_millis(uuids[4_096]), 1_700_000_000_002);
+        assert_eq!(unix_millis(uuids[9_999]), 1_700_000_000_003);
+    }
+
+    #[test]
+    fn test_with_clock() {
+        let generator = V7Generator::with_clock(crate::FixedClock::new(1_700_000_000_000_000_000));
+        let first = generator.generate();
+        let second = generator.generate();
+        assert!(first < second);
+        assert_eq!(unix_millis(first), 1_700_000_000_000);
+        assert_eq!(unix_millis(second), 1_700_000_000_000);
+
+        let before_epoch = V7Generator::with_clock(crate::FixedClock::new(-1));
+        assert!(before_epoch.try_generate().is_err());
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    (0..1_000)
+                        .map(|_| V7Generator::global().generate())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = HashSet::new();
+        for handle in handles {
+            let uuids = handle.join().unwrap();
+            assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+            all.extend(uuids);
+        }
+        assert_eq!(all.len(), 4_000);
+    }
+}
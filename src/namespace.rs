@@ -0,0 +1,231 @@
+//! Submodule defining the [`Namespace`] of the name-based (version 3 and 5)
+//! UUIDs, and their constructors on [`Uuid`].
+
+use core::str::FromStr;
+
+use crate::{Error, Uuid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The namespace in which the name of a name-based UUID is unique.
+///
+/// Besides the predefined namespaces of
+/// [RFC 9562, section 6.6](https://www.rfc-editor.org/rfc/rfc9562#section-6.6),
+/// a namespace can be declared once as a constant from its 128 bits, parsed
+/// from the textual representation of a UUID, or derived from a name within a
+/// parent namespace.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Namespace, Uuid};
+///
+/// // Declared once, as a constant.
+/// const ORDERS: Namespace = Namespace::from_u128(0x6ba7b8a0_9dad_11d1_80b4_00c04fd430c8);
+///
+/// // Or derived from a name, deterministically.
+/// let orders = Namespace::DNS.child("orders.example.com");
+/// assert_eq!(orders, Namespace::DNS.child("orders.example.com"));
+///
+/// let id = Uuid::new_v5(orders, "order-42");
+/// assert_eq!(id, Uuid::new_v5(orders, "order-42"));
+/// assert_ne!(id, Uuid::new_v5(ORDERS, "order-42"));
+/// ```
+pub struct Namespace(Uuid);
+
+impl Namespace {
+    /// The namespace of fully-qualified domain names.
+    pub const DNS: Self = Self(Uuid(uuid::Uuid::NAMESPACE_DNS));
+    /// The namespace of URLs.
+    pub const URL: Self = Self(Uuid(uuid::Uuid::NAMESPACE_URL));
+    /// The namespace of ISO object identifiers.
+    pub const OID: Self = Self(Uuid(uuid::Uuid::NAMESPACE_OID));
+    /// The namespace of X.500 distinguished names, in DER or text format.
+    pub const X500: Self = Self(Uuid(uuid::Uuid::NAMESPACE_X500));
+
+    #[must_use]
+    /// Uses the provided `Uuid` as a namespace.
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    #[must_use]
+    /// Creates the namespace with the provided 128 bits, in big-endian order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Namespace;
+    ///
+    /// const DNS: Namespace = Namespace::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+    /// assert_eq!(DNS, Namespace::DNS);
+    /// ```
+    pub const fn from_u128(value: u128) -> Self {
+        Self(Uuid::from_u128(value))
+    }
+
+    #[must_use]
+    /// Derives the namespace identified by the version 5 UUID of `name` within this namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Namespace, Uuid};
+    ///
+    /// let team = Namespace::URL.child("https://example.com/teams/metabolomics");
+    /// assert_eq!(
+    ///     team.into_uuid(),
+    ///     Uuid::new_v5(Namespace::URL, "https://example.com/teams/metabolomics")
+    /// );
+    /// ```
+    pub fn child(self, name: impl AsRef<[u8]>) -> Self {
+        Self(Uuid::new_v5(self, name))
+    }
+
+    #[must_use]
+    /// Returns a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    #[must_use]
+    /// Returns the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for Namespace {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<Namespace> for Uuid {
+    fn from(namespace: Namespace) -> Self {
+        namespace.0
+    }
+}
+
+impl AsRef<Uuid> for Namespace {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl FromStr for Namespace {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::from_str(s).map(Self)
+    }
+}
+
+impl core::fmt::Display for Namespace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Uuid {
+    #[must_use]
+    /// Creates the name-based version 5 `Uuid` of `name` within `namespace`, using SHA-1.
+    ///
+    /// The same namespace and name always give the same `Uuid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Namespace, Uuid};
+    ///
+    /// let uuid = Uuid::new_v5(Namespace::DNS, "www.example.com");
+    /// assert_eq!(uuid.to_string(), "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+    /// ```
+    pub fn new_v5(namespace: Namespace, name: impl AsRef<[u8]>) -> Self {
+        Self(uuid::Uuid::new_v5(&namespace.0.0, name.as_ref()))
+    }
+
+    #[must_use]
+    /// Creates the name-based version 3 `Uuid` of `name` within `namespace`, using MD5.
+    ///
+    /// Prefer [`Uuid::new_v5`] unless compatibility with existing version 3
+    /// identifiers is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Namespace, Uuid};
+    ///
+    /// let uuid = Uuid::new_v3(Namespace::DNS, "www.example.com");
+    /// assert_eq!(uuid.to_string(), "5df41881-3aed-3515-88a7-2f4a814cf09e");
+    /// ```
+    pub fn new_v3(namespace: Namespace, name: impl AsRef<[u8]>) -> Self {
+        Self(uuid::Uuid::new_v3(&namespace.0.0, name.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn test_predefined_namespaces() {
+        assert_eq!(
+            Namespace::DNS.to_string(),
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Namespace::URL.to_string(),
+            "6ba7b811-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Namespace::OID.to_string(),
+            "6ba7b812-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Namespace::X500.to_string(),
+            "6ba7b814-9dad-11d1-80b4-00c04fd430c8"
+        );
+    }
+
+    #[test]
+    fn test_rfc_vectors() {
+        let v5 = Uuid::new_v5(Namespace::DNS, "www.example.com");
+        assert_eq!(v5.to_string(), "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+        assert_eq!(v5.get_version(), Some(uuid::Version::Sha1));
+
+        let v3 = Uuid::new_v3(Namespace::DNS, "www.example.com");
+        assert_eq!(v3.to_string(), "5df41881-3aed-3515-88a7-2f4a814cf09e");
+        assert_eq!(v3.get_version(), Some(uuid::Version::Md5));
+    }
+
+    #[test]
+    fn test_names_as_bytes_or_strings() {
+        assert_eq!(
+            Uuid::new_v5(Namespace::OID, "1.3.6.1"),
+            Uuid::new_v5(Namespace::OID, b"1.3.6.1")
+        );
+        assert_eq!(
+            Uuid::new_v3(Namespace::X500, String::from("cn=John")),
+            Uuid::new_v3(Namespace::X500, "cn=John".as_bytes())
+        );
+    }
+
+    #[test]
+    fn test_custom_namespaces() {
+        let parsed: Namespace = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+        assert_eq!(parsed, Namespace::DNS);
+        assert!("not-a-namespace".parse::<Namespace>().is_err());
+
+        let child = Namespace::DNS.child("example.com");
+        assert_eq!(
+            child.into_uuid(),
+            Uuid::new_v5(Namespace::DNS, "example.com")
+        );
+        assert_ne!(
+            Uuid::new_v5(child, "name"),
+            Uuid::new_v5(Namespace::DNS, "name")
+        );
+        assert_eq!(Namespace::from(child.into_uuid()), child);
+    }
+}
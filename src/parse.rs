@@ -0,0 +1,187 @@
+//! Submodule implementing the parsing of the textual representations of a UUID,
+//! reporting precisely what was wrong with the rejected inputs.
+
+use crate::Error;
+
+/// The lengths of the accepted textual representations: simple, hyphenated,
+/// braced and URN.
+pub(crate) const TEXT_LENGTHS: [usize; 4] = [32, 36, 38, 45];
+
+/// The positions of the hyphens in the hyphenated representation.
+const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+/// The prefix of the URN representation.
+const URN_PREFIX: &[u8] = b"urn:uuid:";
+
+/// Returns the error describing the character starting at `index` in `input`.
+pub(crate) fn invalid_character(input: &str, index: usize) -> Error {
+    Error::InvalidCharacter {
+        character: input
+            .get(index..)
+            .and_then(|rest| rest.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER),
+        index,
+    }
+}
+
+/// Returns the value of the provided hexadecimal digit.
+pub(crate) const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Checks that the byte at `index` of `input` is the `expected` one.
+fn expect_byte(input: &str, index: usize, expected: u8) -> Result<(), Error> {
+    if input.as_bytes()[index] == expected {
+        Ok(())
+    } else {
+        Err(invalid_character(input, index))
+    }
+}
+
+/// Decodes the hyphenated (when `hyphenated` is true) or simple representation
+/// starting at `offset` in `input`.
+///
+/// The valid inputs are decoded by the vectorized decoders, and the scalar
+/// loop only runs to describe the rejected ones.
+fn decode_hex(input: &str, offset: usize, hyphenated: bool) -> Result<[u8; 16], Error> {
+    let length = if hyphenated { 36 } else { 32 };
+    let digits = &input.as_bytes()[offset..offset + length];
+    let decoded = if hyphenated {
+        digits
+            .try_into()
+            .ok()
+            .and_then(crate::hex::decode_hyphenated)
+    } else {
+        digits.try_into().ok().and_then(crate::hex::decode_simple)
+    };
+    if let Some(bytes) = decoded {
+        return Ok(bytes);
+    }
+    let mut bytes = [0; 16];
+    let mut nibble = 0;
+    for (position, &byte) in input.as_bytes()[offset..offset + length].iter().enumerate() {
+        if hyphenated && HYPHEN_POSITIONS.contains(&position) {
+            expect_byte(input, offset + position, b'-')?;
+            continue;
+        }
+        let value = hex_value(byte).ok_or_else(|| invalid_character(input, offset + position))?;
+        bytes[nibble / 2] |= if nibble % 2 == 0 { value << 4 } else { value };
+        nibble += 1;
+    }
+    Ok(bytes)
+}
+
+/// Parses the simple, hyphenated, braced or URN representation of a UUID.
+pub(crate) fn parse(input: &str) -> Result<[u8; 16], Error> {
+    match input.len() {
+        32 => decode_hex(input, 0, false),
+        36 => decode_hex(input, 0, true),
+        38 => {
+            expect_byte(input, 0, b'{')?;
+            let bytes = decode_hex(input, 1, true)?;
+            expect_byte(input, 37, b'}')?;
+            Ok(bytes)
+        }
+        45 => {
+            for (index, &expected) in URN_PREFIX.iter().enumerate() {
+                expect_byte(input, index, expected)?;
+            }
+            decode_hex(input, URN_PREFIX.len(), true)
+        }
+        found => Err(Error::InvalidLength {
+            expected: &TEXT_LENGTHS,
+            found,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_matches_uuid_crate() {
+        for _ in 0..100 {
+            let uuid = uuid::Uuid::new_v4();
+            let expected = Ok(*uuid.as_bytes());
+            assert_eq!(parse(&uuid.simple().to_string()), expected);
+            assert_eq!(parse(&uuid.hyphenated().to_string()), expected);
+            assert_eq!(parse(&uuid.braced().to_string()), expected);
+            assert_eq!(parse(&uuid.urn().to_string()), expected);
+            assert_eq!(
+                parse(&uuid.hyphenated().to_string().to_uppercase()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert_eq!(
+            parse("67e55044-10b1-426f-9247"),
+            Err(Error::InvalidLength {
+                expected: &TEXT_LENGTHS,
+                found: 23
+            })
+        );
+        assert_eq!(
+            parse(""),
+            Err(Error::InvalidLength {
+                expected: &TEXT_LENGTHS,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_character() {
+        assert_eq!(
+            parse("67e55044-10b1-426f-9247-bb680e5fe0cZ"),
+            Err(Error::InvalidCharacter {
+                character: 'Z',
+                index: 35
+            })
+        );
+        assert_eq!(
+            parse("67e5504410b1-426f-9247-bb680e5fe0c8a"),
+            Err(Error::InvalidCharacter {
+                character: '1',
+                index: 8
+            })
+        );
+        assert_eq!(
+            parse("67e55044-10b1-426f-9247-bb680e5fe0c8()"),
+            Err(Error::InvalidCharacter {
+                character: '6',
+                index: 0
+            })
+        );
+        assert_eq!(
+            parse("{67e55044-10b1-426f-9247-bb680e5fe0c8)"),
+            Err(Error::InvalidCharacter {
+                character: ')',
+                index: 37
+            })
+        );
+        assert_eq!(
+            parse("urn:uuia:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Err(Error::InvalidCharacter {
+                character: 'a',
+                index: 7
+            })
+        );
+        assert_eq!(
+            parse("67e55044-10b1-426f-9247-bb680e5fé0c"),
+            Err(Error::InvalidCharacter {
+                character: 'é',
+                index: 32
+            })
+        );
+    }
+}
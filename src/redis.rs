@@ -18,22 +18,19 @@ impl FromRedisValue for crate::Uuid {
             Value::BulkString(bytes) => {
                 // If it's exactly 16 bytes, assume it is the raw UUID bytes.
                 if bytes.len() == 16 {
-                    return uuid::Uuid::from_slice(&bytes)
-                        .map(crate::Uuid)
-                        .map_err(|_| redis::ParsingError::from("Invalid UUID bytes"));
+                    return crate::Uuid::from_slice(&bytes)
+                        .map_err(|error| redis::ParsingError::from(error.to_string()));
                 }
 
                 // Otherwise try to interpret as a string.
-                let s = core::str::from_utf8(&bytes).map_err(|_| {
-                    redis::ParsingError::from("Invalid UTF-8 sequence for UUID string")
-                })?;
-
-                s.parse::<crate::Uuid>()
-                    .map_err(|_| redis::ParsingError::from("Invalid UUID string"))
+                core::str::from_utf8(&bytes)
+                    .map_err(crate::Error::from)
+                    .and_then(str::parse::<crate::Uuid>)
+                    .map_err(|error| redis::ParsingError::from(error.to_string()))
             }
             Value::SimpleString(s) => s
                 .parse::<crate::Uuid>()
-                .map_err(|_| redis::ParsingError::from("Invalid UUID string")),
+                .map_err(|error| redis::ParsingError::from(error.to_string())),
             _ => Err(redis::ParsingError::from(
                 "Response type not a UUID compatible value",
             )),
@@ -41,6 +38,46 @@ impl FromRedisValue for crate::Uuid {
     }
 }
 
+/// Implements `ToRedisArgs` and `FromRedisValue` for a version-constrained
+/// wrapper, rejecting decoded values of the wrong version or variant.
+macro_rules! impl_redis_for_versioned_uuid {
+    ($name:ty) => {
+        impl ToRedisArgs for $name {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?Sized + RedisWrite,
+            {
+                self.as_uuid().write_redis_args(out);
+            }
+        }
+
+        impl FromRedisValue for $name {
+            fn from_redis_value(v: Value) -> Result<Self, redis::ParsingError> {
+                Self::try_from(crate::Uuid::from_redis_value(v)?)
+                    .map_err(|error| redis::ParsingError::from(error.to_string()))
+            }
+        }
+    };
+}
+
+impl_redis_for_versioned_uuid!(crate::UuidV4);
+impl_redis_for_versioned_uuid!(crate::UuidV7);
+
+impl<P: crate::Prefix> ToRedisArgs for crate::PrefixedUuid<P> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.as_uuid().write_redis_args(out);
+    }
+}
+
+impl<P: crate::Prefix> FromRedisValue for crate::PrefixedUuid<P> {
+    fn from_redis_value(v: Value) -> Result<Self, redis::ParsingError> {
+        crate::Uuid::from_redis_value(v).map(Self::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +156,29 @@ mod tests {
         assert!(crate::Uuid::from_redis_value(val2).is_err());
     }
 
+    #[test]
+    fn test_error_messages() {
+        let val = Value::BulkString(vec![b'a', 0xff, 0xff]);
+        let error = crate::Uuid::from_redis_value(val).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("invalid UTF-8 in UUID after 1 bytes")
+        );
+
+        let val = Value::SimpleString("67e55044-10b1-426f-9247-bb680e5fe0cZ".to_string());
+        let error = crate::Uuid::from_redis_value(val).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("invalid character 'Z' in UUID at position 35")
+        );
+
+        let val = Value::SimpleString("not-a-uuid".to_string());
+        let error = crate::Uuid::from_redis_value(val).unwrap_err();
+        assert!(error.to_string().contains("found 10"));
+    }
+
     #[test]
     fn test_wrong_type() {
         let val = Value::Int(42);
@@ -127,4 +187,39 @@ mod tests {
         let val = Value::Nil;
         assert!(crate::Uuid::from_redis_value(val).is_err());
     }
+
+    #[test]
+    fn test_versioned_roundtrip() {
+        let v7 = crate::UuidV7::now();
+        let val = Value::BulkString(v7.to_redis_args().remove(0));
+        assert_eq!(crate::UuidV7::from_redis_value(val), Ok(v7));
+
+        let v4 = crate::UuidV4::random();
+        let val = Value::BulkString(v4.to_redis_args().remove(0));
+        assert_eq!(crate::UuidV4::from_redis_value(val), Ok(v4));
+    }
+
+    #[test]
+    fn test_versioned_wrong_version() {
+        let val = Value::BulkString(crate::Uuid::new_v4().to_redis_args().remove(0));
+        assert!(crate::UuidV7::from_redis_value(val).is_err());
+
+        let val = Value::SimpleString(crate::Uuid::utc_v7().to_string());
+        assert!(crate::UuidV4::from_redis_value(val).is_err());
+    }
+
+    #[test]
+    fn test_prefixed_stored_as_bytes() {
+        struct User;
+
+        impl crate::Prefix for User {
+            const PREFIX: &'static str = "user";
+        }
+
+        let id = crate::PrefixedUuid::<User>::new(crate::Uuid::utc_v7());
+        let args = id.to_redis_args();
+        assert_eq!(args, id.as_uuid().to_redis_args());
+        let val = Value::BulkString(args[0].clone());
+        assert_eq!(crate::PrefixedUuid::<User>::from_redis_value(val), Ok(id));
+    }
 }
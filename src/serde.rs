@@ -0,0 +1,641 @@
+#![cfg(feature = "serde")]
+//! Serde support for UUIDs, reporting the decoding errors of this crate.
+//!
+//! By default, a [`Uuid`](crate::Uuid) is serialized as its hyphenated string
+//! in human-readable formats and as 16 bytes otherwise. The submodules of this
+//! module select another representation when used with
+//! `#[serde(with = "...")]`, `serialize_with` or `deserialize_with`:
+//!
+//! | Module               | Representation                                  |
+//! |----------------------|-------------------------------------------------|
+//! | [`bytes`]            | 16 bytes                                        |
+//! | [`simple`]           | `67e5504410b1426f9247bb680e5fe0c8`              |
+//! | [`urn`]              | `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8` |
+//! | [`braced`]           | `{67e55044-10b1-426f-9247-bb680e5fe0c8}`        |
+//! | [`compact`]          | a tuple of 16 integers                          |
+//! | [`u128`](mod@u128)   | a 128-bit unsigned integer                      |
+//!
+//! Each module has an `option` and a `vec` submodule, for `Option<Uuid>` and
+//! `Vec<Uuid>` fields. Every deserializer accepts all the shapes the format can
+//! carry: the hyphenated, simple, braced and URN strings, 16 bytes, a sequence
+//! of 16 integers and a 128-bit integer. Note that the `u128` deserializers of
+//! some self-describing formats, such as `serde_json`, only accept numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use rosetta_uuid::Uuid;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Sample {
+//!     #[serde(with = "rosetta_uuid::serde::simple")]
+//!     id: Uuid,
+//!     #[serde(with = "rosetta_uuid::serde::urn::option")]
+//!     parent: Option<Uuid>,
+//!     #[serde(with = "rosetta_uuid::serde::braced::vec")]
+//!     children: Vec<Uuid>,
+//! }
+//!
+//! let id: Uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+//! let sample = Sample { id, parent: Some(id), children: vec![id] };
+//! assert_eq!(
+//!     serde_json::to_string(&sample).unwrap(),
+//!     concat!(
+//!         r#"{"id":"67e5504410b1426f9247bb680e5fe0c8","#,
+//!         r#""parent":"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8","#,
+//!         r#""children":["{67e55044-10b1-426f-9247-bb680e5fe0c8}"]}"#,
+//!     )
+//! );
+//!
+//! // The deserializers accept the other shapes as well.
+//! let sample: Sample = serde_json::from_str(
+//!     r#"{"id":[103,229,80,68,16,177,66,111,146,71,187,104,14,95,224,200],"parent":null,"children":[]}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(sample.id, id);
+//! ```
+
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Serialize, Serializer};
+
+use crate::{Prefix, PrefixedUuid};
+
+/// Visitor decoding a [`crate::Uuid`] from any of its representations.
+struct UuidVisitor;
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = crate::Uuid;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a UUID string, 16 bytes or a 128-bit integer")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        // Anything but 16 bytes may be the UTF-8 encoding of a string.
+        if value.len() == 16 {
+            return crate::Uuid::from_slice(value).map_err(E::custom);
+        }
+        core::str::from_utf8(value)
+            .map_err(crate::Error::from)
+            .and_then(str::parse)
+            .map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        self.visit_u128(value.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, value: core::primitive::u128) -> Result<Self::Value, E> {
+        Ok(uuid::Uuid::from_u128(value).into())
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0; 16];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq.next_element()?.ok_or_else(|| {
+                de::Error::custom(crate::Error::InvalidByteLength { found: index })
+            })?;
+        }
+        let mut found = bytes.len();
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+            found += 1;
+        }
+        if found != bytes.len() {
+            return Err(de::Error::custom(crate::Error::InvalidByteLength { found }));
+        }
+        Ok(bytes.into())
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(self)
+        } else {
+            deserializer.deserialize_bytes(self)
+        }
+    }
+}
+
+/// Deserializes a `Uuid` with `deserialize_any` in human-readable formats,
+/// which are self-describing, so that every shape is accepted, and with the
+/// `hinted` method of the `Deserializer` otherwise.
+fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    hinted: impl FnOnce(D) -> Result<crate::Uuid, D::Error>,
+) -> Result<crate::Uuid, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(UuidVisitor)
+    } else {
+        hinted(deserializer)
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for crate::Uuid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct("Uuid", UuidVisitor)
+    }
+}
+
+impl<P: Prefix> Serialize for PrefixedUuid<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Visitor decoding a [`PrefixedUuid`] from its prefixed textual representation.
+struct PrefixedUuidVisitor<P>(PhantomData<fn() -> P>);
+
+impl<P: Prefix> Visitor<'_> for PrefixedUuidVisitor<P> {
+    type Value = PrefixedUuid<P>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "a TypeID prefixed with {:?}", P::PREFIX)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<'de, P: Prefix> ::serde::Deserialize<'de> for PrefixedUuid<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PrefixedUuidVisitor(PhantomData))
+    }
+}
+
+/// Implements the `option` and `vec` submodules of a representation module,
+/// in terms of the `serialize` and `deserialize` functions of the module.
+macro_rules! option_and_vec {
+    () => {
+        /// A [`Uuid`](crate::Uuid) using the representation of the module.
+        struct Representation(crate::Uuid);
+
+        impl ::serde::Serialize for Representation {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for Representation {
+            fn deserialize<D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserialize(deserializer).map(Self)
+            }
+        }
+
+        /// The representation of the parent module, for `Option<Uuid>` fields.
+        pub mod option {
+            use ::serde::{Deserialize, Serialize};
+
+            use super::Representation;
+
+            #[allow(clippy::ref_option)]
+            /// Serializes an optional `Uuid` with the representation of the parent module.
+            ///
+            /// # Errors
+            ///
+            /// * If the serializer fails.
+            pub fn serialize<S: ::serde::Serializer>(
+                uuid: &Option<crate::Uuid>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                uuid.map(Representation).serialize(serializer)
+            }
+
+            /// Deserializes an optional `Uuid`, accepting the same inputs as the
+            /// parent module.
+            ///
+            /// # Errors
+            ///
+            /// * If the input is neither null nor a valid UUID.
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<crate::Uuid>, D::Error> {
+                Ok(Option::<Representation>::deserialize(deserializer)?.map(|uuid| uuid.0))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        /// The representation of the parent module, for `Vec<Uuid>` fields.
+        pub mod vec {
+            use ::serde::Deserialize;
+
+            use super::Representation;
+
+            /// Serializes a sequence of `Uuid` with the representation of the parent module.
+            ///
+            /// # Errors
+            ///
+            /// * If the serializer fails.
+            pub fn serialize<S: ::serde::Serializer>(
+                uuids: &[crate::Uuid],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(uuids.iter().copied().map(Representation))
+            }
+
+            /// Deserializes a sequence of `Uuid`, accepting the same inputs as the
+            /// parent module.
+            ///
+            /// # Errors
+            ///
+            /// * If the input is not a sequence of valid UUIDs.
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<crate::Uuid>, D::Error> {
+                Ok(Vec::<Representation>::deserialize(deserializer)?
+                    .into_iter()
+                    .map(|uuid| uuid.0)
+                    .collect())
+            }
+        }
+    };
+}
+
+/// Defines a representation module, serializing with the provided closure and
+/// deserializing with the provided method of the `Deserializer`.
+macro_rules! representation {
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        |$uuid:ident, $serializer:ident| $serialize:expr,
+        |$deserializer:ident, $visitor:ident| $deserialize:expr
+    ) => {
+        representation!(
+            $(#[$meta])*
+            $module,
+            |$uuid, $serializer| $serialize,
+            |$deserializer, $visitor| $deserialize,
+            "Deserializes a `Uuid` from any of its representations."
+        );
+    };
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        |$uuid:ident, $serializer:ident| $serialize:expr,
+        |$deserializer:ident, $visitor:ident| $deserialize:expr,
+        $deserialize_doc:literal
+    ) => {
+        $(#[$meta])*
+        pub mod $module {
+            use ::serde::{Deserializer, Serializer};
+
+            /// Serializes a `Uuid` with the representation of this module.
+            ///
+            /// # Errors
+            ///
+            /// * If the serializer fails.
+            pub fn serialize<S: Serializer>(
+                $uuid: &crate::Uuid,
+                $serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $serialize
+            }
+
+            #[doc = $deserialize_doc]
+            ///
+            /// # Errors
+            ///
+            /// * If the input is not a valid UUID.
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                $deserializer: D,
+            ) -> Result<crate::Uuid, D::Error> {
+                let $visitor = super::UuidVisitor;
+                $deserialize
+            }
+
+            option_and_vec!();
+        }
+    };
+}
+
+representation!(
+    /// Represents a `Uuid` as 16 bytes, in every format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Sample(#[serde(with = "rosetta_uuid::serde::bytes")] Uuid);
+    ///
+    /// let id = Uuid::from([1; 16]);
+    /// let json = serde_json::to_string(&Sample(id)).unwrap();
+    /// assert_eq!(json, serde_json::to_string(&[1; 16]).unwrap());
+    /// assert_eq!(serde_json::from_str::<Sample>(&json).unwrap().0, id);
+    /// ```
+    bytes,
+    |uuid, serializer| serializer.serialize_bytes(uuid.as_bytes()),
+    |deserializer, visitor| super::deserialize_lenient(deserializer, |deserializer| deserializer.deserialize_bytes(visitor))
+);
+
+representation!(
+    /// Represents a `Uuid` as 32 hexadecimal digits without hyphens, such as
+    /// `67e5504410b1426f9247bb680e5fe0c8`.
+    simple,
+    |uuid, serializer| serializer.collect_str(&uuid.simple()),
+    |deserializer, visitor| super::deserialize_lenient(deserializer, |deserializer| deserializer.deserialize_str(visitor))
+);
+
+representation!(
+    /// Represents a `Uuid` as a URN, such as
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    urn,
+    |uuid, serializer| serializer.collect_str(&uuid.urn()),
+    |deserializer, visitor| super::deserialize_lenient(deserializer, |deserializer| deserializer.deserialize_str(visitor))
+);
+
+representation!(
+    /// Represents a `Uuid` as a hyphenated string between braces, such as
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    braced,
+    |uuid, serializer| serializer.collect_str(&uuid.braced()),
+    |deserializer, visitor| super::deserialize_lenient(deserializer, |deserializer| deserializer.deserialize_str(visitor))
+);
+
+representation!(
+    /// Represents a `Uuid` as a tuple of 16 integers, which binary formats
+    /// encode without a length prefix.
+    compact,
+    |uuid, serializer| ::serde::Serialize::serialize(uuid.as_bytes(), serializer),
+    |deserializer, visitor| super::deserialize_lenient(deserializer, |deserializer| deserializer.deserialize_tuple(16, visitor))
+);
+
+representation!(
+    /// Represents a `Uuid` as a 128-bit unsigned integer.
+    ///
+    /// Unlike the other representations, this one only deserializes integers.
+    /// Self-describing formats such as JSON decode the integers beyond 64 bits,
+    /// which most UUIDs are, only when explicitly asked for a 128-bit integer,
+    /// and would otherwise round them to a float: the deserializer therefore
+    /// cannot also accept strings, bytes or sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Sample(#[serde(with = "rosetta_uuid::serde::u128")] Uuid);
+    ///
+    /// let id = Uuid::from_u128(42);
+    /// assert_eq!(serde_json::to_string(&Sample(id)).unwrap(), "42");
+    /// assert_eq!(serde_json::from_str::<Sample>("42").unwrap().0, id);
+    /// ```
+    u128,
+    |uuid, serializer| serializer.serialize_u128(uuid.as_u128()),
+    |deserializer, visitor| deserializer.deserialize_u128(visitor),
+    "Deserializes a `Uuid` from a 128-bit unsigned integer, the only input accepted by this representation."
+);
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::Uuid;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_json_roundtrip() {
+        let uuid = Uuid::new_v4();
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, format!("\"{uuid}\""));
+        assert_eq!(serde_json::from_str::<Uuid>(&json).unwrap(), uuid);
+    }
+
+    #[test]
+    fn test_json_error_messages() {
+        let error = serde_json::from_str::<Uuid>("\"67e55044-10b1-426f-9247-bb680e5fe0cZ\"")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("invalid character 'Z' in UUID at position 35"));
+
+        let error = serde_json::from_str::<Uuid>("\"67e55044\"")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("found 8"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_prefixed_json_roundtrip() {
+        use crate::{Prefix, PrefixedUuid};
+
+        struct User;
+
+        impl Prefix for User {
+            const PREFIX: &'static str = "user";
+        }
+
+        let id = PrefixedUuid::<User>::new(Uuid::utc_v7());
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{id}\""));
+        assert!(json.starts_with("\"user_"));
+        assert_eq!(
+            serde_json::from_str::<PrefixedUuid<User>>(&json).unwrap(),
+            id
+        );
+
+        let error =
+            serde_json::from_str::<PrefixedUuid<User>>("\"team_01h455vb4pex5vsknk084sn02q\"")
+                .unwrap_err()
+                .to_string();
+        assert!(error.contains("expected a UUID prefixed with \"user\""));
+    }
+
+    mod representations {
+        use alloc::boxed::Box;
+        use alloc::format;
+        use alloc::string::ToString;
+        use alloc::vec;
+        use serde::{Deserialize, Serialize};
+        use serde_test::{Configure, Token, assert_de_tokens, assert_tokens};
+
+        use crate::Uuid;
+
+        const TEXT: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+        fn uuid() -> Uuid {
+            TEXT.parse().unwrap()
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Bytes(#[serde(with = "crate::serde::bytes")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Simple(#[serde(with = "crate::serde::simple")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Urn(#[serde(with = "crate::serde::urn")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Braced(#[serde(with = "crate::serde::braced")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Compact(#[serde(with = "crate::serde::compact")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Integer(#[serde(with = "crate::serde::u128")] Uuid);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Optional(#[serde(with = "crate::serde::simple::option")] Option<Uuid>);
+
+        #[cfg(feature = "std")]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Sequence(#[serde(with = "crate::serde::compact::vec")] Vec<Uuid>);
+
+        fn newtype(name: &'static str, token: Token) -> [Token; 2] {
+            [Token::NewtypeStruct { name }, token]
+        }
+
+        #[test]
+        fn test_serialized_shapes() {
+            let bytes = uuid().into_bytes();
+            let bytes: &'static [u8] = Box::leak(Box::new(bytes));
+            assert_tokens(
+                &Bytes(uuid()).readable(),
+                &newtype("Bytes", Token::Bytes(bytes)),
+            );
+            assert_tokens(
+                &Simple(uuid()).compact(),
+                &newtype("Simple", Token::Str("67e5504410b1426f9247bb680e5fe0c8")),
+            );
+            assert_tokens(
+                &Urn(uuid()).compact(),
+                &newtype(
+                    "Urn",
+                    Token::Str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                ),
+            );
+            assert_tokens(
+                &Braced(uuid()).compact(),
+                &newtype(
+                    "Braced",
+                    Token::Str("{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+                ),
+            );
+            assert_eq!(
+                serde_json::to_string(&Integer(uuid())).unwrap(),
+                uuid().as_u128().to_string()
+            );
+
+            let mut tokens = vec![
+                Token::NewtypeStruct { name: "Compact" },
+                Token::Tuple { len: 16 },
+            ];
+            tokens.extend(uuid().as_bytes().iter().map(|&byte| Token::U8(byte)));
+            tokens.push(Token::TupleEnd);
+            assert_tokens(&Compact(uuid()).readable(), &tokens);
+        }
+
+        #[test]
+        fn test_lenient_deserializers() {
+            for shape in [
+                Token::Str(TEXT),
+                Token::Str("67e5504410b1426f9247bb680e5fe0c8"),
+                Token::Str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Token::Bytes(TEXT.as_bytes()),
+            ] {
+                let tokens = newtype("Bytes", shape);
+                assert_de_tokens(&Bytes(uuid()).readable(), &tokens);
+                let tokens = newtype("Simple", shape);
+                assert_de_tokens(&Simple(uuid()).readable(), &tokens);
+                let tokens = newtype("Compact", shape);
+                assert_de_tokens(&Compact(uuid()).readable(), &tokens);
+            }
+
+            let small = Uuid::from_u128(42);
+            assert_de_tokens(&Bytes(small).readable(), &newtype("Bytes", Token::U64(42)));
+            assert_eq!(
+                serde_json::from_str::<Integer>(&uuid().as_u128().to_string()).unwrap(),
+                Integer(uuid())
+            );
+
+            let json = format!("{:?}", uuid().as_bytes());
+            assert_eq!(serde_json::from_str::<Urn>(&json).unwrap(), Urn(uuid()));
+            assert_eq!(
+                serde_json::from_str::<Compact>(&format!("\"{TEXT}\"")).unwrap(),
+                Compact(uuid())
+            );
+        }
+
+        #[test]
+        fn test_option() {
+            assert_eq!(serde_json::to_string(&Optional(None)).unwrap(), "null");
+            assert_eq!(
+                serde_json::to_string(&Optional(Some(uuid()))).unwrap(),
+                "\"67e5504410b1426f9247bb680e5fe0c8\""
+            );
+            assert_eq!(
+                serde_json::from_str::<Optional>(&format!("\"{TEXT}\"")).unwrap(),
+                Optional(Some(uuid()))
+            );
+            assert_eq!(
+                serde_json::from_str::<Optional>("null").unwrap(),
+                Optional(None)
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn test_vec() {
+            let uuids = Sequence(vec![uuid(), Uuid::new_v4()]);
+            let json = serde_json::to_string(&uuids).unwrap();
+            assert!(json.starts_with("[[103,229,"));
+            assert_eq!(serde_json::from_str::<Sequence>(&json).unwrap(), uuids);
+            assert_eq!(
+                serde_json::from_str::<Sequence>(&format!("[\"{TEXT}\"]")).unwrap(),
+                Sequence(vec![uuid()])
+            );
+        }
+
+        #[test]
+        fn test_integer_only_accepts_integers() {
+            // Integers beyond 64 bits keep every bit.
+            let max = Uuid::from_u128(u128::MAX);
+            assert_eq!(
+                serde_json::from_str::<Integer>(&u128::MAX.to_string()).unwrap(),
+                Integer(max)
+            );
+            assert_de_tokens(
+                &Integer(Uuid::from_u128(42)),
+                &newtype("Integer", Token::U64(42)),
+            );
+
+            // The other shapes are rejected, as documented.
+            assert!(serde_json::from_str::<Integer>(&format!("\"{TEXT}\"")).is_err());
+            assert!(serde_json::from_str::<Integer>(&format!("{:?}", uuid().as_bytes())).is_err());
+        }
+
+        #[test]
+        fn test_error_messages() {
+            let error = serde_json::from_str::<Compact>("[1, 2, 3]")
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("invalid UUID length: expected 16 bytes, found 3"));
+
+            let error = serde_json::from_str::<Compact>(&format!("{:?}", [0; 17]))
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("found 17"));
+
+            let error = serde_json::from_str::<Simple>("\"67e5504410b1426f9247bb680e5fe0cZ\"")
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("invalid character 'Z' in UUID at position 31"));
+        }
+    }
+}
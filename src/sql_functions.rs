@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+//! Submodule implementing the UUID SQL functions independently of any
+//! database driver.
+//!
+//! Both `sqlite::register_functions`, with the `sqlite` feature, and the
+//! `rosetta-uuid-sqlite` loadable extension call these functions, so that a
+//! query returns the same values whether it runs through Diesel or in the
+//! `sqlite3` shell. As with the built-in functions of SQL, `None` arguments
+//! return `None`.
+
+use crate::{Namespace, Uuid};
+
+/// Creates a random version 4 UUID.
+#[must_use]
+pub fn uuid_v4() -> Uuid {
+    Uuid::new_v4()
+}
+
+/// Creates a version 7 UUID with the current time, greater than any other
+/// created within the process.
+#[must_use]
+pub fn uuid_v7() -> Uuid {
+    Uuid::monotonic_v7()
+}
+
+/// Creates the name-based version 5 UUID of `name` within `namespace`.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::sql_functions::uuid_v5;
+/// use rosetta_uuid::{Namespace, Uuid};
+///
+/// assert_eq!(
+///     uuid_v5(Some(Namespace::DNS.into_uuid()), Some("www.example.com")),
+///     Some(Uuid::new_v5(Namespace::DNS, "www.example.com"))
+/// );
+/// assert_eq!(uuid_v5(None, Some("www.example.com")), None);
+/// ```
+#[must_use]
+pub fn uuid_v5(namespace: Option<Uuid>, name: Option<&str>) -> Option<Uuid> {
+    Some(Uuid::new_v5(Namespace::from_uuid(namespace?), name?))
+}
+
+/// Formats the UUID in the lowercase hyphenated representation.
+#[must_use]
+pub fn uuid_to_text(uuid: Option<Uuid>) -> Option<String> {
+    uuid.map(|uuid| uuid.to_string())
+}
+
+/// Parses the simple, hyphenated, braced or URN representation of a UUID, or
+/// returns `None` if the text is not a valid UUID.
+#[must_use]
+pub fn uuid_from_text(text: Option<&str>) -> Option<Uuid> {
+    text?.parse().ok()
+}
+
+/// Returns the version number of the UUID.
+#[must_use]
+pub fn uuid_version(uuid: Option<Uuid>) -> Option<i32> {
+    i32::try_from(uuid?.get_version_num()).ok()
+}
+
+/// Returns the creation time of a time-based (v1, v6 or v7) UUID, in
+/// milliseconds since the Unix epoch, or `None` for the other versions.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+///
+/// use rosetta_uuid::Uuid;
+/// use rosetta_uuid::sql_functions::uuid_timestamp;
+///
+/// let created_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+/// let v7 = Uuid::v7_from(created_at).unwrap();
+/// assert_eq!(uuid_timestamp(Some(v7)), Some(1_700_000_000_123));
+/// assert_eq!(uuid_timestamp(Some(Uuid::new_v4())), None);
+/// ```
+#[must_use]
+pub fn uuid_timestamp(uuid: Option<Uuid>) -> Option<i64> {
+    let (seconds, nanos) = uuid?.get_timestamp()?.to_unix();
+    i64::try_from(seconds)
+        .ok()?
+        .checked_mul(1_000)?
+        .checked_add(i64::from(nanos / 1_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generation() {
+        assert_eq!(uuid_v4().get_version_num(), 4);
+        let first = uuid_v7();
+        let second = uuid_v7();
+        assert_eq!(first.get_version_num(), 7);
+        assert!(first < second);
+        assert_eq!(uuid_v5(Some(Namespace::DNS.into_uuid()), None), None);
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        for text in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E5504410B1426F9247BB680E5FE0C8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(uuid_from_text(Some(text)), Some(uuid));
+        }
+        assert_eq!(
+            uuid_to_text(Some(uuid)).as_deref(),
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(uuid_from_text(Some("not a uuid")), None);
+        assert_eq!(uuid_from_text(None), None);
+        assert_eq!(uuid_to_text(None), None);
+    }
+
+    #[test]
+    fn test_version_and_timestamp() {
+        let v1 = Uuid::from_u128(0xc232ab00_9414_11ec_b3c8_9f6bdeced846);
+        assert_eq!(uuid_version(Some(v1)), Some(1));
+        assert_eq!(uuid_timestamp(Some(v1)), Some(1_645_557_742_000));
+        assert_eq!(uuid_version(Some(Uuid::nil())), Some(0));
+        assert_eq!(uuid_version(None), None);
+        assert_eq!(uuid_timestamp(None), None);
+    }
+}
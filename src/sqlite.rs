@@ -0,0 +1,300 @@
+#![cfg(feature = "sqlite")]
+//! Submodule providing the SQL functions on UUIDs for SQLite, which has no
+//! UUID support of its own, and their declarations for the Diesel DSL.
+//!
+//! [`register_functions`] installs all of them on a connection. The UUIDs are
+//! read and written as 16-byte BLOBs, as with the
+//! [`Uuid`](crate::diesel_impls::Uuid) SQL type, and `NULL` arguments return
+//! `NULL`, as with the built-in functions of SQLite. The functions are
+//! implemented in [`crate::sql_functions`], which the
+//! `rosetta-uuid-sqlite` loadable extension shares.
+
+use diesel::QueryResult;
+use diesel::sql_types::{BigInt, Integer, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::Uuid;
+use crate::diesel_impls::Uuid as SqlUuid;
+use crate::sql_functions;
+
+#[diesel::declare_sql_function]
+extern "SQL" {
+    /// Creates a random version 4 UUID.
+    fn uuid_v4() -> crate::diesel_impls::Uuid;
+
+    /// Creates a version 7 UUID with the current time, greater than any other
+    /// created within the process.
+    fn uuid_v7() -> crate::diesel_impls::Uuid;
+
+    /// Creates the name-based version 5 UUID of `name` within `namespace`.
+    fn uuid_v5(
+        namespace: diesel::sql_types::Nullable<crate::diesel_impls::Uuid>,
+        name: diesel::sql_types::Nullable<diesel::sql_types::Text>,
+    ) -> diesel::sql_types::Nullable<crate::diesel_impls::Uuid>;
+
+    /// Formats the UUID in the lowercase hyphenated representation.
+    fn uuid_to_text(
+        uuid: diesel::sql_types::Nullable<crate::diesel_impls::Uuid>,
+    ) -> diesel::sql_types::Nullable<diesel::sql_types::Text>;
+
+    /// Parses the simple, hyphenated, braced or URN representation of a UUID,
+    /// or returns `NULL` if the text is not a valid UUID.
+    fn uuid_from_text(
+        text: diesel::sql_types::Nullable<diesel::sql_types::Text>,
+    ) -> diesel::sql_types::Nullable<crate::diesel_impls::Uuid>;
+
+    /// Returns the version number of the UUID.
+    fn uuid_version(
+        uuid: diesel::sql_types::Nullable<crate::diesel_impls::Uuid>,
+    ) -> diesel::sql_types::Nullable<diesel::sql_types::Integer>;
+
+    /// Returns the creation time of a time-based (v1, v6 or v7) UUID, in
+    /// milliseconds since the Unix epoch, or `NULL` for the other versions.
+    fn uuid_timestamp(
+        uuid: diesel::sql_types::Nullable<crate::diesel_impls::Uuid>,
+    ) -> diesel::sql_types::Nullable<diesel::sql_types::BigInt>;
+}
+
+/// Registers the UUID functions of this module on the provided connection:
+/// `uuid_v4()`, `uuid_v7()`, `uuid_v5(namespace, name)`, `uuid_to_text(uuid)`,
+/// `uuid_from_text(text)`, `uuid_version(uuid)` and `uuid_timestamp(uuid)`.
+///
+/// Once registered, the functions can be used in raw SQL, including in the
+/// `DEFAULT` clauses and triggers of the tables, and through their Diesel
+/// declarations in typed queries.
+///
+/// # Errors
+///
+/// * If SQLite fails to register a function.
+///
+/// # Examples
+///
+/// ```
+/// use diesel::prelude::*;
+/// use diesel::sqlite::SqliteConnection;
+/// use rosetta_uuid::Uuid;
+/// use rosetta_uuid::sqlite::{register_functions, uuid_to_text, uuid_v7};
+///
+/// let mut connection = SqliteConnection::establish(":memory:").unwrap();
+/// register_functions(&mut connection).unwrap();
+///
+/// diesel::sql_query("CREATE TABLE samples (id BLOB PRIMARY KEY DEFAULT (uuid_v7()), name TEXT)")
+///     .execute(&mut connection)
+///     .unwrap();
+/// diesel::sql_query("INSERT INTO samples (name) VALUES ('first')")
+///     .execute(&mut connection)
+///     .unwrap();
+///
+/// let id: Uuid = diesel::select(uuid_v7()).get_result(&mut connection).unwrap();
+/// assert_eq!(id.get_version_num(), 7);
+///
+/// let text: Option<String> = diesel::select(uuid_to_text(Some(id)))
+///     .get_result(&mut connection)
+///     .unwrap();
+/// assert_eq!(text, Some(id.to_string()));
+/// ```
+pub fn register_functions(connection: &mut SqliteConnection) -> QueryResult<()> {
+    // The `register_impl` helpers generated by `declare_sql_function` are only
+    // emitted for the built-in SQL types, so the functions are registered
+    // directly, as those helpers would.
+    connection.register_noarg_sql_function::<SqlUuid, _, _>(
+        "uuid_v4",
+        false,
+        sql_functions::uuid_v4,
+    )?;
+    connection.register_noarg_sql_function::<SqlUuid, _, _>(
+        "uuid_v7",
+        false,
+        sql_functions::uuid_v7,
+    )?;
+    connection
+        .register_sql_function::<(Nullable<SqlUuid>, Nullable<Text>), Nullable<SqlUuid>, _, _, _>(
+            "uuid_v5",
+            true,
+            |(namespace, name): (Option<Uuid>, Option<String>)| {
+                sql_functions::uuid_v5(namespace, name.as_deref())
+            },
+        )?;
+    connection.register_sql_function::<(Nullable<SqlUuid>,), Nullable<Text>, _, _, _>(
+        "uuid_to_text",
+        true,
+        |(uuid,): (Option<Uuid>,)| sql_functions::uuid_to_text(uuid),
+    )?;
+    connection.register_sql_function::<(Nullable<Text>,), Nullable<SqlUuid>, _, _, _>(
+        "uuid_from_text",
+        true,
+        |(text,): (Option<String>,)| sql_functions::uuid_from_text(text.as_deref()),
+    )?;
+    connection.register_sql_function::<(Nullable<SqlUuid>,), Nullable<Integer>, _, _, _>(
+        "uuid_version",
+        true,
+        |(uuid,): (Option<Uuid>,)| sql_functions::uuid_version(uuid),
+    )?;
+    connection.register_sql_function::<(Nullable<SqlUuid>,), Nullable<BigInt>, _, _, _>(
+        "uuid_timestamp",
+        true,
+        |(uuid,): (Option<Uuid>,)| sql_functions::uuid_timestamp(uuid),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Namespace;
+    use diesel::prelude::*;
+
+    fn connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        register_functions(&mut connection).unwrap();
+        connection
+    }
+
+    #[derive(QueryableByName, Debug)]
+    struct Row {
+        #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+        value: Option<String>,
+    }
+
+    fn query_text(connection: &mut SqliteConnection, sql: &str) -> Option<String> {
+        diesel::sql_query(sql)
+            .get_result::<Row>(connection)
+            .unwrap()
+            .value
+    }
+
+    #[test]
+    fn test_generation() {
+        let mut connection = connection();
+        let v4: Uuid = diesel::select(uuid_v4())
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(v4.get_version(), Some(uuid::Version::Random));
+
+        let first: Uuid = diesel::select(uuid_v7())
+            .get_result(&mut connection)
+            .unwrap();
+        let second: Uuid = diesel::select(uuid_v7())
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(first.get_version(), Some(uuid::Version::SortRand));
+        assert!(first < second);
+
+        let v5: Option<Uuid> = diesel::select(uuid_v5(
+            Some(Namespace::DNS.into_uuid()),
+            Some("www.example.com"),
+        ))
+        .get_result(&mut connection)
+        .unwrap();
+        assert_eq!(v5, Some(Uuid::new_v5(Namespace::DNS, "www.example.com")));
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut connection = connection();
+        let uuid = Uuid::new_v4();
+        let text: Option<String> = diesel::select(uuid_to_text(Some(uuid)))
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(text, Some(uuid.to_string()));
+
+        let parsed: Option<Uuid> = diesel::select(uuid_from_text(Some(
+            uuid.simple().to_string().to_uppercase(),
+        )))
+        .get_result(&mut connection)
+        .unwrap();
+        assert_eq!(parsed, Some(uuid));
+
+        assert_eq!(
+            query_text(
+                &mut connection,
+                "SELECT uuid_to_text(uuid_from_text('{67e55044-10b1-426f-9247-bb680e5fe0c8}')) AS value"
+            ),
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned())
+        );
+        assert_eq!(
+            query_text(
+                &mut connection,
+                "SELECT uuid_to_text(uuid_from_text('not a uuid')) AS value"
+            ),
+            None
+        );
+        assert_eq!(
+            query_text(&mut connection, "SELECT uuid_to_text(NULL) AS value"),
+            None
+        );
+        assert!(
+            diesel::sql_query("SELECT uuid_to_text(X'0102') AS value")
+                .get_result::<Row>(&mut connection)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_version_and_timestamp() {
+        let mut connection = connection();
+        let created_at =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1_700_000_000_123);
+        let v7 = Uuid::v7_from(created_at).unwrap();
+
+        let (version, timestamp): (Option<i32>, Option<i64>) =
+            diesel::select((uuid_version(Some(v7)), uuid_timestamp(Some(v7))))
+                .get_result(&mut connection)
+                .unwrap();
+        assert_eq!(version, Some(7));
+        assert_eq!(timestamp, Some(1_700_000_000_123));
+
+        let v4 = Uuid::new_v4();
+        let (version, timestamp): (Option<i32>, Option<i64>) =
+            diesel::select((uuid_version(Some(v4)), uuid_timestamp(Some(v4))))
+                .get_result(&mut connection)
+                .unwrap();
+        assert_eq!(version, Some(4));
+        assert_eq!(timestamp, None);
+    }
+
+    diesel::table! {
+        samples (id) {
+            id -> crate::diesel_impls::Uuid,
+            name -> Text,
+        }
+    }
+
+    #[test]
+    fn test_default_and_trigger() {
+        let mut connection = connection();
+        diesel::sql_query(
+            "CREATE TABLE samples (id BLOB PRIMARY KEY DEFAULT (uuid_v7()), name TEXT NOT NULL)",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        diesel::sql_query(
+            "CREATE TABLE audit (sample TEXT NOT NULL, version INTEGER NOT NULL);
+            ",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        diesel::sql_query(
+            "CREATE TRIGGER audit_samples AFTER INSERT ON samples BEGIN
+                INSERT INTO audit VALUES (uuid_to_text(NEW.id), uuid_version(NEW.id));
+            END",
+        )
+        .execute(&mut connection)
+        .unwrap();
+
+        diesel::insert_into(samples::table)
+            .values(samples::name.eq("first"))
+            .execute(&mut connection)
+            .unwrap();
+
+        let (id, text) = samples::table
+            .select((samples::id, uuid_to_text(samples::id.nullable())))
+            .get_result::<(Uuid, Option<String>)>(&mut connection)
+            .unwrap();
+        assert_eq!(id.get_version(), Some(uuid::Version::SortRand));
+        assert_eq!(text, Some(id.to_string()));
+        assert_eq!(
+            query_text(&mut connection, "SELECT sample AS value FROM audit"),
+            Some(id.to_string())
+        );
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "time")]
+//! Submodule providing conversions between the timestamps embedded in
+//! time-based UUIDs and [`time`] types.
+
+use ::time::OffsetDateTime;
+
+use crate::{FixedClock, UnixTimestamp};
+
+impl UnixTimestamp for OffsetDateTime {
+    fn unix_timestamp_nanos(&self) -> i128 {
+        OffsetDateTime::unix_timestamp_nanos(*self)
+    }
+
+    fn from_unix_timestamp_nanos(unix_timestamp_nanos: i128) -> Option<Self> {
+        OffsetDateTime::from_unix_timestamp_nanos(unix_timestamp_nanos).ok()
+    }
+}
+
+impl From<OffsetDateTime> for FixedClock {
+    fn from(date_time: OffsetDateTime) -> Self {
+        Self::new(date_time.unix_timestamp_nanos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uuid;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_round_trip() {
+        let created_at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let uuid = Uuid::v7_from(created_at).unwrap();
+        assert_eq!(uuid.created_at_as::<OffsetDateTime>(), Some(created_at));
+        assert!(Uuid::v7_lower_bound(created_at) <= uuid);
+        assert!(uuid <= Uuid::v7_upper_bound(created_at));
+        assert_eq!(
+            Uuid::v7_lower_bound(created_at).to_string(),
+            "018bcfe5-6800-7000-8000-000000000000"
+        );
+        assert_eq!(Uuid::new_v4().created_at_as::<OffsetDateTime>(), None);
+    }
+
+    #[test]
+    fn test_v7_from_random_bytes() {
+        let created_at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let uuid = Uuid::v7_from_random_bytes(created_at, [0xFF; 10]).unwrap();
+        assert_eq!(uuid.created_at_as::<OffsetDateTime>(), Some(created_at));
+        assert_eq!(uuid, Uuid::v7_upper_bound(created_at));
+        assert_eq!(
+            Uuid::v7_from_random_bytes(OffsetDateTime::from_unix_timestamp(-1).unwrap(), [0; 10]),
+            Err(crate::Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1_000_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        let date_time = OffsetDateTime::from_unix_timestamp_nanos(-1).unwrap();
+        assert_eq!(UnixTimestamp::unix_timestamp_nanos(&date_time), -1);
+        assert_eq!(
+            <OffsetDateTime as UnixTimestamp>::from_unix_timestamp_nanos(i128::MAX),
+            None
+        );
+        assert_eq!(FixedClock::from(date_time), FixedClock::new(-1));
+    }
+}
@@ -0,0 +1,245 @@
+//! Submodule defining the [`UnixTimestamp`] trait, implemented by the instant
+//! types of the supported date and time crates, and the constructors and
+//! accessors of [`Uuid`] which are generic over it.
+//!
+//! The `chrono`, `time` and `jiff` features each implement the trait for the
+//! instant type of their crate, and [`std::time::SystemTime`] implements it
+//! with the `std` feature.
+
+use crate::{Error, FixedClock, Uuid};
+
+/// An instant which can be converted to and from the number of nanoseconds
+/// since the Unix epoch.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::UnixTimestamp;
+/// use std::time::{Duration, SystemTime};
+///
+/// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// assert_eq!(time.unix_timestamp_nanos(), 1_700_000_000_000_000_000);
+/// assert_eq!(
+///     SystemTime::from_unix_timestamp_nanos(1_700_000_000_000_000_000),
+///     Some(time)
+/// );
+/// ```
+pub trait UnixTimestamp: Copy {
+    /// Returns the instant, as the number of nanoseconds since the Unix epoch.
+    fn unix_timestamp_nanos(&self) -> i128;
+
+    /// Creates the instant from the number of nanoseconds since the Unix epoch,
+    /// or returns `None` if it is out of the range of the type.
+    fn from_unix_timestamp_nanos(unix_timestamp_nanos: i128) -> Option<Self>;
+}
+
+#[cfg(feature = "std")]
+impl UnixTimestamp for std::time::SystemTime {
+    fn unix_timestamp_nanos(&self) -> i128 {
+        match self.duration_since(Self::UNIX_EPOCH) {
+            Ok(after) => i128::try_from(after.as_nanos()).unwrap_or(i128::MAX),
+            Err(before) => {
+                i128::try_from(before.duration().as_nanos()).map_or(i128::MIN, |nanos| -nanos)
+            }
+        }
+    }
+
+    fn from_unix_timestamp_nanos(unix_timestamp_nanos: i128) -> Option<Self> {
+        let nanos = unix_timestamp_nanos.unsigned_abs();
+        let duration = std::time::Duration::new(
+            u64::try_from(nanos / 1_000_000_000).ok()?,
+            u32::try_from(nanos % 1_000_000_000).ok()?,
+        );
+        if unix_timestamp_nanos < 0 {
+            Self::UNIX_EPOCH.checked_sub(duration)
+        } else {
+            Self::UNIX_EPOCH.checked_add(duration)
+        }
+    }
+}
+
+/// Returns the millisecond of the provided instant, clamped to the range
+/// representable in the 48 bits of the timestamp of a version 7 UUID.
+fn v7_unix_millis<T: UnixTimestamp>(time: &T) -> u64 {
+    u64::try_from(time.unix_timestamp_nanos().div_euclid(1_000_000))
+        .unwrap_or_default()
+        .min((1 << 48) - 1)
+}
+
+impl Uuid {
+    #[must_use]
+    /// Returns the smallest version 7 `Uuid` which can be created during the
+    /// millisecond of the provided instant.
+    ///
+    /// Together with [`Uuid::v7_upper_bound`], it turns a time range into a
+    /// range of primary keys. Times before the Unix epoch are clamped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let uuid = Uuid::v7_from(created_at).unwrap();
+    ///
+    /// assert!(Uuid::v7_lower_bound(created_at) <= uuid);
+    /// assert!(uuid <= Uuid::v7_upper_bound(created_at));
+    /// assert_eq!(
+    ///     Uuid::v7_lower_bound(created_at).to_string(),
+    ///     "018bcfe5-6800-7000-8000-000000000000"
+    /// );
+    /// ```
+    pub fn v7_lower_bound<T: UnixTimestamp>(time: T) -> Self {
+        uuid::Builder::from_unix_timestamp_millis(v7_unix_millis(&time), &[0x00; 10])
+            .into_uuid()
+            .into()
+    }
+
+    #[must_use]
+    /// Returns the largest version 7 `Uuid` which can be created during the
+    /// millisecond of the provided instant.
+    ///
+    /// Together with [`Uuid::v7_lower_bound`], it turns a time range into a
+    /// range of primary keys. Times before the Unix epoch are clamped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// assert_eq!(
+    ///     Uuid::v7_upper_bound(time).to_string(),
+    ///     "018bcfe5-6800-7fff-bfff-ffffffffffff"
+    /// );
+    /// ```
+    pub fn v7_upper_bound<T: UnixTimestamp>(time: T) -> Self {
+        uuid::Builder::from_unix_timestamp_millis(v7_unix_millis(&time), &[0xFF; 10])
+            .into_uuid()
+            .into()
+    }
+
+    #[cfg(feature = "std")]
+    /// Creates a new version 7 `Uuid` with the millisecond of the provided instant.
+    ///
+    /// Without the `std` feature, use [`Uuid::v7_from_random_bytes`] with the
+    /// entropy of the platform instead.
+    ///
+    /// # Errors
+    ///
+    /// * If the instant is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let created_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    /// let uuid = Uuid::v7_from(created_at).unwrap();
+    /// assert_eq!(uuid.created_at_as::<SystemTime>(), Some(created_at));
+    ///
+    /// assert!(Uuid::v7_from(SystemTime::UNIX_EPOCH - Duration::from_secs(1)).is_err());
+    /// ```
+    pub fn v7_from<T: UnixTimestamp>(time: T) -> Result<Self, Error> {
+        Self::try_v7_with_clock(&FixedClock::new(time.unix_timestamp_nanos()))
+    }
+
+    /// Creates a new version 7 `Uuid` with the millisecond of the provided
+    /// instant and caller-provided random bytes.
+    ///
+    /// The version and variant bits are overwritten, so 74 of the 80 provided
+    /// bits are kept. This is the constructor to use without the `std`
+    /// feature, with the entropy of the platform.
+    ///
+    /// # Errors
+    ///
+    /// * If the instant is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let created_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    /// let uuid = Uuid::v7_from_random_bytes(created_at, [0; 10]).unwrap();
+    /// assert_eq!(uuid.to_string(), "018bcfe5-687b-7000-8000-000000000000");
+    /// assert_eq!(uuid.created_at_as::<SystemTime>(), Some(created_at));
+    /// ```
+    pub fn v7_from_random_bytes<T: UnixTimestamp>(
+        time: T,
+        random_bytes: [u8; 10],
+    ) -> Result<Self, Error> {
+        Self::try_v7_from_random_bytes(&FixedClock::new(time.unix_timestamp_nanos()), random_bytes)
+    }
+
+    #[must_use]
+    /// Returns the time embedded in a time-based (v1, v6 or v7) `Uuid` as the
+    /// requested instant type, or `None` for the other versions and for the
+    /// times out of the range of the type.
+    ///
+    /// The precision is the one of the version: milliseconds for v7, and
+    /// 100 nanoseconds for v1 and v6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::Uuid;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let uuid = Uuid::v7_from(created_at).unwrap();
+    /// assert_eq!(uuid.created_at_as::<SystemTime>(), Some(created_at));
+    ///
+    /// assert_eq!(Uuid::new_v4().created_at_as::<SystemTime>(), None);
+    /// ```
+    pub fn created_at_as<T: UnixTimestamp>(&self) -> Option<T> {
+        let (seconds, nanos) = self.get_timestamp()?.to_unix();
+        T::from_unix_timestamp_nanos(i128::from(seconds) * 1_000_000_000 + i128::from(nanos))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_system_time_round_trip() {
+        for unix_timestamp_nanos in [
+            0,
+            1,
+            -1,
+            1_700_000_000_123_456_789,
+            -1_700_000_000_123_456_789,
+        ] {
+            let time = SystemTime::from_unix_timestamp_nanos(unix_timestamp_nanos).unwrap();
+            assert_eq!(time.unix_timestamp_nanos(), unix_timestamp_nanos);
+        }
+        assert_eq!(
+            SystemTime::UNIX_EPOCH - Duration::from_nanos(1),
+            SystemTime::from_unix_timestamp_nanos(-1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_v7_from() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+        let uuid = Uuid::v7_from(time).unwrap();
+        assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+        assert_eq!(
+            uuid.created_at_as::<SystemTime>(),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123))
+        );
+        assert!(Uuid::v7_lower_bound(time) <= uuid && uuid <= Uuid::v7_upper_bound(time));
+        assert_eq!(
+            Uuid::v7_from(SystemTime::UNIX_EPOCH - Duration::from_nanos(1)),
+            Err(Error::TimeBeforeUnixEpoch {
+                unix_timestamp_nanos: -1
+            })
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! Submodule defining the [`PrefixedUuid`] type, a [`Uuid`] tagged with the
+//! kind of entity it identifies, following the
+//! [TypeID specification](https://github.com/jetify-com/typeid/tree/main/spec).
+//!
+//! The prefix only exists in the textual representation: Diesel and Redis
+//! store the bare 16 bytes, exactly as for a [`Uuid`].
+
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use crate::encoding::CrockfordBase32;
+use crate::{Error, Uuid};
+
+/// The lowercase Crockford base32 alphabet of the TypeID suffix.
+const SUFFIX_ALPHABET: &[u8] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// The maximum length of a TypeID prefix.
+const MAX_PREFIX_LENGTH: usize = 63;
+
+/// Returns whether the provided prefix is a valid TypeID prefix: at most 63
+/// characters, made of lowercase ASCII letters and underscores, neither
+/// starting nor ending with an underscore.
+const fn is_valid_prefix(prefix: &str) -> bool {
+    let bytes = prefix.as_bytes();
+    if bytes.len() > MAX_PREFIX_LENGTH {
+        return false;
+    }
+    if let [b'_', ..] | [.., b'_'] = bytes {
+        return false;
+    }
+    let mut index = 0;
+    while index < bytes.len() {
+        if !matches!(bytes[index], b'a'..=b'z' | b'_') {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// The prefix of the identifiers of a kind of entity.
+///
+/// The prefix must be a valid TypeID prefix: at most 63 characters, made of
+/// lowercase ASCII letters and underscores, neither starting nor ending with
+/// an underscore. It may be empty, in which case the identifiers are rendered
+/// without a separator. An invalid prefix is rejected at compile time when a
+/// [`PrefixedUuid`] using it is built.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::Prefix;
+///
+/// #[derive(Debug)]
+/// struct User;
+///
+/// impl Prefix for User {
+///     const PREFIX: &'static str = "user";
+/// }
+/// ```
+///
+/// An invalid prefix does not compile:
+///
+/// ```compile_fail
+/// use rosetta_uuid::{Prefix, PrefixedUuid, Uuid};
+///
+/// struct Invalid;
+///
+/// impl Prefix for Invalid {
+///     const PREFIX: &'static str = "User";
+/// }
+///
+/// let id = PrefixedUuid::<Invalid>::new(Uuid::new_v4());
+/// ```
+pub trait Prefix {
+    /// The prefix of the identifiers.
+    const PREFIX: &'static str;
+}
+
+/// A [`Uuid`] rendered as a [TypeID](https://github.com/jetify-com/typeid),
+/// such as `user_01h455vb4pex5vsknk084sn02q`.
+///
+/// The textual representation is the prefix of `P`, an underscore, and the
+/// 26 characters of the lowercase Crockford base32 encoding of the UUID.
+/// Parsing checks the prefix, and only accepts the lowercase alphabet.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Prefix, PrefixedUuid, Uuid};
+///
+/// #[derive(Debug)]
+/// struct User;
+///
+/// impl Prefix for User {
+///     const PREFIX: &'static str = "user";
+/// }
+///
+/// let id: PrefixedUuid<User> = "user_01h455vb4pex5vsknk084sn02q".parse().unwrap();
+/// assert_eq!(id.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+/// assert_eq!(id.into_uuid().to_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
+///
+/// assert!("team_01h455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>().is_err());
+/// ```
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = crate::diesel_impls::Uuid))]
+#[cfg_attr(feature = "postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "sqlite", diesel(sql_type = crate::diesel_impls::LenientUuid))]
+pub struct PrefixedUuid<P: Prefix> {
+    /// The identifier.
+    uuid: Uuid,
+    /// The kind of entity identified.
+    prefix: PhantomData<fn() -> P>,
+}
+
+impl<P: Prefix> PrefixedUuid<P> {
+    /// Checks at compile time that the prefix is a valid TypeID prefix.
+    const VALID_PREFIX: () = assert!(
+        is_valid_prefix(P::PREFIX),
+        "a TypeID prefix must be at most 63 lowercase ASCII letters or underscores, and must neither start nor end with an underscore"
+    );
+
+    #[must_use]
+    /// Tags the provided `Uuid` with the prefix of `P`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Prefix, PrefixedUuid, Uuid};
+    ///
+    /// struct Order;
+    ///
+    /// impl Prefix for Order {
+    ///     const PREFIX: &'static str = "order";
+    /// }
+    ///
+    /// let uuid = Uuid::utc_v7();
+    /// let id = PrefixedUuid::<Order>::new(uuid);
+    /// assert_eq!(id.as_uuid(), &uuid);
+    /// assert!(id.to_string().starts_with("order_"));
+    /// ```
+    pub const fn new(uuid: Uuid) -> Self {
+        let () = Self::VALID_PREFIX;
+        Self {
+            uuid,
+            prefix: PhantomData,
+        }
+    }
+
+    #[must_use]
+    /// Returns a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    #[must_use]
+    /// Returns the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+
+    /// Writes the TypeID suffix of the `Uuid` into the provided buffer, returning it as a string.
+    fn encode_suffix<'b>(&self, buffer: &'b mut [u8; CrockfordBase32::LENGTH]) -> &'b str {
+        self.uuid.crockford_base32().encode(buffer);
+        buffer.make_ascii_lowercase();
+        core::str::from_utf8(buffer).expect("the alphabet is ASCII")
+    }
+}
+
+impl<P: Prefix> Clone for PrefixedUuid<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Prefix> Copy for PrefixedUuid<P> {}
+
+impl<P: Prefix> PartialEq for PrefixedUuid<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<P: Prefix> Eq for PrefixedUuid<P> {}
+
+impl<P: Prefix> PartialOrd for PrefixedUuid<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Prefix> Ord for PrefixedUuid<P> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<P: Prefix> Hash for PrefixedUuid<P> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state);
+    }
+}
+
+impl<P: Prefix> Debug for PrefixedUuid<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PrefixedUuid")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl<P: Prefix> Display for PrefixedUuid<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !P::PREFIX.is_empty() {
+            f.write_str(P::PREFIX)?;
+            f.write_str("_")?;
+        }
+        f.write_str(self.encode_suffix(&mut [0; CrockfordBase32::LENGTH]))
+    }
+}
+
+impl<P: Prefix> FromStr for PrefixedUuid<P> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let suffix = if P::PREFIX.is_empty() {
+            Some(s)
+        } else {
+            s.strip_prefix(P::PREFIX)
+                .and_then(|rest| rest.strip_prefix('_'))
+        };
+        let suffix = suffix.ok_or(Error::UnexpectedPrefix {
+            expected: P::PREFIX,
+        })?;
+        let offset = s.len() - suffix.len();
+        if suffix.len() != CrockfordBase32::LENGTH {
+            return Err(Error::InvalidLength {
+                expected: &[CrockfordBase32::LENGTH],
+                found: suffix.len(),
+            });
+        }
+        if let Some(index) = suffix
+            .bytes()
+            .position(|byte| !SUFFIX_ALPHABET.contains(&byte))
+        {
+            return Err(crate::parse::invalid_character(s, offset + index));
+        }
+        Uuid::parse_crockford_base32(suffix).map(Self::new)
+    }
+}
+
+impl<P: Prefix> From<Uuid> for PrefixedUuid<P> {
+    fn from(uuid: Uuid) -> Self {
+        Self::new(uuid)
+    }
+}
+
+impl<P: Prefix> From<PrefixedUuid<P>> for Uuid {
+    fn from(id: PrefixedUuid<P>) -> Self {
+        id.uuid
+    }
+}
+
+impl<P: Prefix> AsRef<Uuid> for PrefixedUuid<P> {
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl<P: Prefix> core::ops::Deref for PrefixedUuid<P> {
+    type Target = Uuid;
+
+    fn deref(&self) -> &Self::Target {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug)]
+    struct User;
+
+    impl Prefix for User {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[derive(Debug)]
+    struct Unprefixed;
+
+    impl Prefix for Unprefixed {
+        const PREFIX: &'static str = "";
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug)]
+    struct ApiKey;
+
+    #[cfg(feature = "std")]
+    impl Prefix for ApiKey {
+        const PREFIX: &'static str = "api_key";
+    }
+
+    #[test]
+    fn test_is_valid_prefix() {
+        assert!(is_valid_prefix(""));
+        assert!(is_valid_prefix("user"));
+        assert!(is_valid_prefix("api_key"));
+        assert!(is_valid_prefix(&"a".repeat(63)));
+        assert!(!is_valid_prefix(&"a".repeat(64)));
+        assert!(!is_valid_prefix("_user"));
+        assert!(!is_valid_prefix("user_"));
+        assert!(!is_valid_prefix("_"));
+        assert!(!is_valid_prefix("User"));
+        assert!(!is_valid_prefix("user1"));
+    }
+
+    #[test]
+    fn test_spec_examples() {
+        let id: PrefixedUuid<User> = "user_01h455vb4pex5vsknk084sn02q".parse().unwrap();
+        assert_eq!(
+            id.as_uuid().to_string(),
+            "01890a5d-ac96-774b-bcce-b302099a8057"
+        );
+
+        let nil = PrefixedUuid::<Unprefixed>::new(Uuid::default());
+        assert_eq!(nil.to_string(), "00000000000000000000000000");
+
+        let max = PrefixedUuid::<Unprefixed>::new(Uuid::from([0xFF; 16]));
+        assert_eq!(max.to_string(), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_roundtrip() {
+        let id = PrefixedUuid::<ApiKey>::new(Uuid::utc_v7());
+        let text = id.to_string();
+        assert!(text.starts_with("api_key_"));
+        assert_eq!(text.parse(), Ok(id));
+        assert_eq!(format!("{id:?}"), format!("PrefixedUuid({text})"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_inputs() {
+        assert_eq!(
+            "team_01h455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>(),
+            Err(Error::UnexpectedPrefix { expected: "user" })
+        );
+        assert_eq!(
+            "user01h455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>(),
+            Err(Error::UnexpectedPrefix { expected: "user" })
+        );
+        assert_eq!(
+            "user_01h455vb4pex5vsknk084sn02".parse::<PrefixedUuid<User>>(),
+            Err(Error::InvalidLength {
+                expected: &[26],
+                found: 25
+            })
+        );
+        assert_eq!(
+            "user_01H455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>(),
+            Err(Error::InvalidCharacter {
+                character: 'H',
+                index: 7
+            })
+        );
+        assert_eq!(
+            "user_o1h455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>(),
+            Err(Error::InvalidCharacter {
+                character: 'o',
+                index: 5
+            })
+        );
+        assert_eq!(
+            "user_81h455vb4pex5vsknk084sn02q".parse::<PrefixedUuid<User>>(),
+            Err(Error::Overflow)
+        );
+        assert!(
+            "_01h455vb4pex5vsknk084sn02q"
+                .parse::<PrefixedUuid<Unprefixed>>()
+                .is_err()
+        );
+    }
+}
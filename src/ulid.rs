@@ -0,0 +1,66 @@
+#![cfg(feature = "ulid")]
+//! Submodule implementing the lossless conversions between UUIDs and the
+//! [`Ulid`] type of the [`ulid`](https://crates.io/crates/ulid) crate.
+//!
+//! The conversions keep the 128 bits as they are, so they preserve the
+//! ordering: the ULIDs of a table can be converted and inserted in a
+//! `Uuid`-keyed table without changing the order of its rows.
+
+use ulid::Ulid;
+
+impl From<Ulid> for crate::Uuid {
+    fn from(ulid: Ulid) -> Self {
+        uuid::Uuid::from_u128(ulid.0).into()
+    }
+}
+
+impl From<crate::Uuid> for Ulid {
+    fn from(uuid: crate::Uuid) -> Self {
+        Ulid(uuid.as_u128())
+    }
+}
+
+impl From<crate::UuidV7> for Ulid {
+    fn from(uuid: crate::UuidV7) -> Self {
+        uuid.into_uuid().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uuid;
+    use alloc::vec::Vec;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_roundtrip() {
+        let original = Ulid::from_string("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        let converted = Uuid::from(original);
+        assert_eq!(converted.to_ulid_string(), original.to_string());
+        assert_eq!(Ulid::from(converted), original);
+    }
+
+    #[test]
+    fn test_preserves_ordering() {
+        let mut originals: Vec<Ulid> = (0..100_u128)
+            .map(|index| Ulid(index.wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835)))
+            .collect();
+        originals.sort();
+        let converted: Vec<Uuid> = originals.iter().copied().map(Uuid::from).collect();
+        assert!(converted.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_v7() {
+        let v7 = crate::UuidV7::now();
+        let ulid = Ulid::from(v7);
+        assert_eq!(ulid.to_string(), v7.to_ulid_string());
+        let (seconds, nanos) = v7.get_timestamp().unwrap().to_unix();
+        assert_eq!(
+            ulid.timestamp_ms(),
+            seconds * 1000 + u64::from(nanos) / 1_000_000
+        );
+    }
+}
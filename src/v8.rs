@@ -0,0 +1,473 @@
+//! Submodule implementing custom (version 8) UUIDs made of named bit fields.
+//!
+//! [RFC 9562, section 5.8](https://www.rfc-editor.org/rfc/rfc9562#section-5.8)
+//! leaves 122 of the 128 bits of a version 8 UUID to the application, around
+//! the 4 version bits and the 2 variant bits. A [`V8Layout`] names the fields
+//! packed into those 122 bits, from the most significant one, so that the
+//! [`V8Builder`] and [`V8Layout::decode`] can skip the version and variant bits
+//! on behalf of the caller.
+
+use crate::{Error, Uuid};
+
+/// Number of bits available to the application in a version 8 UUID.
+const V8_PAYLOAD_BITS: u32 = 122;
+
+/// Number of payload bits stored before the version bits (`custom_a`).
+const CUSTOM_A_BITS: u32 = 48;
+
+/// Number of payload bits stored between the version and variant bits (`custom_b`).
+const CUSTOM_B_BITS: u32 = 12;
+
+/// Number of payload bits stored after the variant bits (`custom_c`).
+const CUSTOM_C_BITS: u32 = 62;
+
+/// Returns the mask of the lower `width` bits.
+const fn mask(width: u32) -> u128 {
+    if width >= u128::BITS {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Returns whether the two strings are equal, in a const context.
+const fn const_str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < left.len() {
+        if left[index] != right[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Packs the 122-bit payload into a version 8 UUID of the RFC 9562 variant.
+const fn encode_payload(payload: u128) -> u128 {
+    let custom_a = payload >> (CUSTOM_B_BITS + CUSTOM_C_BITS);
+    let custom_b = (payload >> CUSTOM_C_BITS) & mask(CUSTOM_B_BITS);
+    let custom_c = payload & mask(CUSTOM_C_BITS);
+    (custom_a << (u128::BITS - CUSTOM_A_BITS))
+        | (0x8 << 76)
+        | (custom_b << 64)
+        | (0b10 << 62)
+        | custom_c
+}
+
+/// Extracts the 122-bit payload of a version 8 UUID.
+const fn decode_payload(value: u128) -> u128 {
+    let custom_a = value >> (u128::BITS - CUSTOM_A_BITS);
+    let custom_b = (value >> 64) & mask(CUSTOM_B_BITS);
+    let custom_c = value & mask(CUSTOM_C_BITS);
+    (custom_a << (CUSTOM_B_BITS + CUSTOM_C_BITS)) | (custom_b << CUSTOM_C_BITS) | custom_c
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A named bit field of a [`V8Layout`].
+pub struct V8Field {
+    /// The name of the field.
+    name: &'static str,
+    /// The number of bits of the field.
+    width: u32,
+}
+
+impl V8Field {
+    #[must_use]
+    /// Creates a field with the provided name and number of bits.
+    pub const fn new(name: &'static str, width: u32) -> Self {
+        Self { name, width }
+    }
+
+    #[must_use]
+    /// Returns the name of the field.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    /// Returns the number of bits of the field.
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The layout of the 122 application-defined bits of a version 8 UUID, as a
+/// sequence of named fields starting from the most significant bit.
+///
+/// The bits not covered by any field are the least significant ones, and are
+/// left to zero by the [`V8Builder`].
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{V8Field, V8Layout};
+///
+/// const LAYOUT: V8Layout = V8Layout::new(&[
+///     V8Field::new("timestamp", 48),
+///     V8Field::new("shard", 10),
+///     V8Field::new("entity", 6),
+///     V8Field::new("tenant", 32),
+/// ]);
+///
+/// let uuid = LAYOUT
+///     .builder()
+///     .set("timestamp", 1_700_000_000_000)?
+///     .set("shard", 513)?
+///     .set("entity", 7)?
+///     .set("tenant", 0xCAFE)?
+///     .build();
+/// assert_eq!(uuid.get_version(), Some(uuid::Version::Custom));
+/// assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+///
+/// let fields = LAYOUT.decode(&uuid)?;
+/// assert_eq!(fields.get("shard"), Some(513));
+/// assert_eq!(fields.get("tenant"), Some(0xCAFE));
+/// # Ok::<(), rosetta_uuid::Error>(())
+/// ```
+pub struct V8Layout<'a> {
+    /// The fields, from the most significant one.
+    fields: &'a [V8Field],
+}
+
+impl<'a> V8Layout<'a> {
+    #[must_use]
+    /// Creates the layout made of the provided fields, from the most significant one.
+    ///
+    /// # Panics
+    ///
+    /// * If a field is empty or two fields share the same name.
+    /// * If the fields add up to more than 122 bits.
+    ///
+    /// When the layout is declared as a constant, these are compile-time errors.
+    ///
+    /// ```compile_fail
+    /// use rosetta_uuid::{V8Field, V8Layout};
+    ///
+    /// const LAYOUT: V8Layout = V8Layout::new(&[V8Field::new("a", 64), V8Field::new("b", 64)]);
+    /// ```
+    pub const fn new(fields: &'a [V8Field]) -> Self {
+        let mut total: u32 = 0;
+        let mut index = 0;
+        while index < fields.len() {
+            assert!(fields[index].width > 0, "a UUIDv8 field must not be empty");
+            assert!(
+                fields[index].width <= V8_PAYLOAD_BITS,
+                "the UUIDv8 fields must fit in 122 bits"
+            );
+            let mut other = 0;
+            while other < index {
+                assert!(
+                    !const_str_eq(fields[index].name, fields[other].name),
+                    "the UUIDv8 field names must be unique"
+                );
+                other += 1;
+            }
+            total = match total.checked_add(fields[index].width) {
+                Some(total) => total,
+                None => panic!("the UUIDv8 fields must fit in 122 bits"),
+            };
+            index += 1;
+        }
+        assert!(
+            total <= V8_PAYLOAD_BITS,
+            "the UUIDv8 fields must fit in 122 bits"
+        );
+        Self { fields }
+    }
+
+    #[must_use]
+    /// Returns the fields of the layout, from the most significant one.
+    pub const fn fields(&self) -> &'a [V8Field] {
+        self.fields
+    }
+
+    /// Returns the field with the provided name and its shift within the payload.
+    fn locate(&self, name: &str) -> Option<(V8Field, u32)> {
+        let mut offset = 0;
+        self.fields.iter().find_map(|field| {
+            offset += field.width;
+            (field.name == name).then_some((*field, V8_PAYLOAD_BITS - offset))
+        })
+    }
+
+    #[must_use]
+    /// Returns a builder of version 8 UUIDs with this layout, with every field set to zero.
+    pub const fn builder(&self) -> V8Builder<'_, 'a> {
+        V8Builder {
+            layout: self,
+            payload: 0,
+        }
+    }
+
+    /// Decodes the fields of a version 8 UUID with this layout.
+    ///
+    /// # Errors
+    ///
+    /// * If the UUID is not of the RFC 9562 variant.
+    /// * If the UUID is not a version 8 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, Uuid, V8Field, V8Layout};
+    ///
+    /// const LAYOUT: V8Layout = V8Layout::new(&[V8Field::new("kind", 8)]);
+    ///
+    /// assert_eq!(
+    ///     LAYOUT.decode(&Uuid::new_v4()).unwrap_err(),
+    ///     Error::UnexpectedVersion {
+    ///         expected: uuid::Version::Custom,
+    ///         found: Some(uuid::Version::Random),
+    ///     }
+    /// );
+    /// ```
+    pub fn decode(&self, uuid: &Uuid) -> Result<V8Fields<'_, 'a>, Error> {
+        let variant = uuid.get_variant();
+        if variant != uuid::Variant::RFC4122 {
+            return Err(Error::UnexpectedVariant { found: variant });
+        }
+        let found = uuid.get_version();
+        if found != Some(uuid::Version::Custom) {
+            return Err(Error::UnexpectedVersion {
+                expected: uuid::Version::Custom,
+                found,
+            });
+        }
+        Ok(V8Fields {
+            layout: self,
+            payload: decode_payload(uuid.as_u128()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Builder of version 8 UUIDs with the fields of a [`V8Layout`].
+///
+/// See [`V8Layout`] for an example.
+pub struct V8Builder<'l, 'a> {
+    /// The layout of the fields.
+    layout: &'l V8Layout<'a>,
+    /// The 122 application-defined bits.
+    payload: u128,
+}
+
+impl V8Builder<'_, '_> {
+    /// Sets the value of the field with the provided name.
+    ///
+    /// # Errors
+    ///
+    /// * If the layout has no field with the provided name.
+    /// * If the value does not fit in the bits of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{Error, V8Field, V8Layout};
+    ///
+    /// const LAYOUT: V8Layout = V8Layout::new(&[V8Field::new("shard", 4)]);
+    ///
+    /// assert!(LAYOUT.builder().set("shard", 15).is_ok());
+    /// assert_eq!(
+    ///     LAYOUT.builder().set("shard", 16),
+    ///     Err(Error::FieldOverflow { name: "shard", width: 4 })
+    /// );
+    /// assert_eq!(
+    ///     LAYOUT.builder().set("tenant", 1),
+    ///     Err(Error::UnknownField { name: "tenant" })
+    /// );
+    /// ```
+    pub fn set(mut self, name: &'static str, value: u128) -> Result<Self, Error> {
+        let (field, shift) = self
+            .layout
+            .locate(name)
+            .ok_or(Error::UnknownField { name })?;
+        if value > mask(field.width) {
+            return Err(Error::FieldOverflow {
+                name,
+                width: field.width,
+            });
+        }
+        self.payload = (self.payload & !(mask(field.width) << shift)) | (value << shift);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the version 8 `Uuid` holding the values of the fields.
+    pub fn build(self) -> Uuid {
+        uuid::Uuid::from_u128(encode_payload(self.payload)).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The fields of a version 8 UUID, decoded with a [`V8Layout`].
+pub struct V8Fields<'l, 'a> {
+    /// The layout of the fields.
+    layout: &'l V8Layout<'a>,
+    /// The 122 application-defined bits.
+    payload: u128,
+}
+
+impl V8Fields<'_, '_> {
+    #[must_use]
+    /// Returns the value of the field with the provided name, if the layout has one.
+    pub fn get(&self, name: &str) -> Option<u128> {
+        self.layout
+            .locate(name)
+            .map(|(field, shift)| (self.payload >> shift) & mask(field.width))
+    }
+
+    /// Returns the names and values of the fields, from the most significant one.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u128)> + '_ {
+        self.layout
+            .fields
+            .iter()
+            .filter_map(|field| Some((field.name, self.get(field.name)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    const LAYOUT: V8Layout = V8Layout::new(&[
+        V8Field::new("a", 48),
+        V8Field::new("b", 12),
+        V8Field::new("c", 62),
+    ]);
+
+    const SPANNING: V8Layout = V8Layout::new(&[
+        V8Field::new("head", 40),
+        V8Field::new("middle", 30),
+        V8Field::new("tail", 20),
+    ]);
+
+    #[test]
+    fn test_rfc_layout() {
+        // The custom_a, custom_b and custom_c fields of the RFC 9562 example.
+        let uuid = LAYOUT
+            .builder()
+            .set("a", 0x2489_E9AD_2EE2)
+            .unwrap()
+            .set("b", 0xE00)
+            .unwrap()
+            .set("c", 0x0EC9_32D5_F691_81C0)
+            .unwrap()
+            .build();
+        assert_eq!(uuid.to_string(), "2489e9ad-2ee2-8e00-8ec9-32d5f69181c0");
+
+        let fields = LAYOUT.decode(&uuid).unwrap();
+        assert_eq!(fields.get("a"), Some(0x2489_E9AD_2EE2));
+        assert_eq!(fields.get("b"), Some(0xE00));
+        assert_eq!(fields.get("c"), Some(0x0EC9_32D5_F691_81C0));
+        assert_eq!(fields.get("d"), None);
+    }
+
+    #[test]
+    fn test_fields_spanning_version_and_variant() {
+        let uuid = SPANNING
+            .builder()
+            .set("head", mask(40))
+            .unwrap()
+            .set("middle", mask(30))
+            .unwrap()
+            .set("tail", mask(20))
+            .unwrap()
+            .build();
+        assert_eq!(uuid.get_version(), Some(uuid::Version::Custom));
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+
+        let fields: Vec<_> = SPANNING.decode(&uuid).unwrap().iter().collect();
+        assert_eq!(
+            fields,
+            vec![("head", mask(40)), ("middle", mask(30)), ("tail", mask(20))]
+        );
+    }
+
+    #[test]
+    fn test_set_overwrites() {
+        let uuid = SPANNING
+            .builder()
+            .set("middle", mask(30))
+            .unwrap()
+            .set("middle", 5)
+            .unwrap()
+            .build();
+        let fields = SPANNING.decode(&uuid).unwrap();
+        assert_eq!(fields.get("head"), Some(0));
+        assert_eq!(fields.get("middle"), Some(5));
+        assert_eq!(fields.get("tail"), Some(0));
+    }
+
+    #[test]
+    fn test_payload_roundtrip() {
+        for payload in [
+            0,
+            1,
+            mask(V8_PAYLOAD_BITS),
+            0x0123_4567_89AB_CDEF_0123_4567_89AB_CDEF,
+        ] {
+            assert_eq!(decode_payload(encode_payload(payload)), payload);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_errors() {
+        assert_eq!(
+            LAYOUT.builder().set("b", 1 << 12),
+            Err(Error::FieldOverflow {
+                name: "b",
+                width: 12
+            })
+        );
+        assert_eq!(
+            LAYOUT.builder().set("z", 0),
+            Err(Error::UnknownField { name: "z" })
+        );
+        assert_eq!(
+            LAYOUT.decode(&Uuid::utc_v7()),
+            Err(Error::UnexpectedVersion {
+                expected: uuid::Version::Custom,
+                found: Some(uuid::Version::SortRand)
+            })
+        );
+        assert!(LAYOUT.decode(&Uuid::default()).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicate_names() {
+        let fields = [V8Field::new("a", 1), V8Field::new("a", 1)];
+        let _ = V8Layout::new(&fields);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_field() {
+        let fields = [V8Field::new("a", 0)];
+        let _ = V8Layout::new(&fields);
+    }
+
+    #[test]
+    #[should_panic(expected = "the UUIDv8 fields must fit in 122 bits")]
+    fn test_oversized_field() {
+        let fields = [V8Field::new("a", 123)];
+        let _ = V8Layout::new(&fields);
+    }
+
+    #[test]
+    #[should_panic(expected = "the UUIDv8 fields must fit in 122 bits")]
+    fn test_widths_overflowing_u32() {
+        // The sum of the widths wraps around to 122 without the checks.
+        let fields = [V8Field::new("a", u32::MAX), V8Field::new("b", 123)];
+        let _ = V8Layout::new(&fields);
+    }
+}
@@ -0,0 +1,372 @@
+//! Submodule defining the [`UuidV4`] and [`UuidV7`] wrappers, which only
+//! accept UUIDs of the RFC 9562 variant and of their own version.
+//!
+//! Every boundary through which a [`Uuid`] can be built (parsing, byte
+//! conversions, serde, Diesel and Redis) checks the version and variant, so
+//! that a column declared as holding version 7 UUIDs cannot silently receive
+//! a version 4 UUID written by another service.
+
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use crate::{Clock, V7Generator};
+use crate::{Error, Uuid};
+
+/// Returns the provided `Uuid` if it is of the RFC 9562 variant and of the expected version.
+fn check_version(uuid: Uuid, expected: uuid::Version) -> Result<Uuid, Error> {
+    let variant = uuid.get_variant();
+    if variant != uuid::Variant::RFC4122 {
+        return Err(Error::UnexpectedVariant { found: variant });
+    }
+    let found = uuid.get_version();
+    if found != Some(expected) {
+        return Err(Error::UnexpectedVersion { expected, found });
+    }
+    Ok(uuid)
+}
+
+/// Implements the traits shared by the version-constrained wrappers.
+macro_rules! impl_versioned_uuid {
+    ($name:ident, $version:expr) => {
+        impl $name {
+            /// The version of the UUIDs accepted by this type.
+            pub const VERSION: uuid::Version = $version;
+
+            #[must_use]
+            /// Returns a reference to the underlying [`Uuid`].
+            pub const fn as_uuid(&self) -> &Uuid {
+                &self.0
+            }
+
+            #[must_use]
+            /// Returns the underlying [`Uuid`].
+            pub const fn into_uuid(self) -> Uuid {
+                self.0
+            }
+        }
+
+        impl TryFrom<Uuid> for $name {
+            type Error = Error;
+
+            fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+                check_version(uuid, Self::VERSION).map(Self)
+            }
+        }
+
+        impl TryFrom<uuid::Uuid> for $name {
+            type Error = Error;
+
+            fn try_from(uuid: uuid::Uuid) -> Result<Self, Self::Error> {
+                Self::try_from(Uuid::from(uuid))
+            }
+        }
+
+        impl TryFrom<[u8; 16]> for $name {
+            type Error = Error;
+
+            fn try_from(bytes: [u8; 16]) -> Result<Self, Self::Error> {
+                Self::try_from(Uuid::from(bytes))
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(uuid: $name) -> Self {
+                uuid.0
+            }
+        }
+
+        impl From<$name> for uuid::Uuid {
+            fn from(uuid: $name) -> Self {
+                uuid.0.into()
+            }
+        }
+
+        impl From<$name> for [u8; 16] {
+            fn from(uuid: $name) -> Self {
+                uuid.0.into()
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(Uuid::from_str(s)?)
+            }
+        }
+
+        impl AsRef<Uuid> for $name {
+            fn as_ref(&self) -> &Uuid {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8; 16]> for $name {
+            fn as_ref(&self) -> &[u8; 16] {
+                self.0.as_ref()
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = Uuid;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "Uuid", into = "Uuid")
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = crate::diesel_impls::Uuid))]
+#[cfg_attr(feature = "postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "sqlite", diesel(sql_type = crate::diesel_impls::LenientUuid))]
+/// A [`Uuid`] guaranteed to be a random (version 4) UUID.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Uuid, UuidV4};
+/// use std::str::FromStr;
+///
+/// let uuid = UuidV4::random();
+/// let parsed = UuidV4::from_str(&uuid.to_string()).unwrap();
+/// assert_eq!(uuid, parsed);
+///
+/// assert!(UuidV4::try_from(Uuid::utc_v7()).is_err());
+/// ```
+pub struct UuidV4(Uuid);
+
+impl_versioned_uuid!(UuidV4, uuid::Version::Random);
+
+impl UuidV4 {
+    #[must_use]
+    #[cfg(feature = "std")]
+    /// Creates a new random `UuidV4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::UuidV4;
+    ///
+    /// let uuid = UuidV4::random();
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::Random));
+    /// ```
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "Uuid", into = "Uuid")
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = crate::diesel_impls::Uuid))]
+#[cfg_attr(feature = "postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+#[cfg_attr(feature = "sqlite", diesel(sql_type = crate::diesel_impls::LenientUuid))]
+/// A [`Uuid`] guaranteed to be a timestamp-based (version 7) UUID.
+///
+/// # Examples
+///
+/// ```
+/// use rosetta_uuid::{Uuid, UuidV7};
+/// use std::str::FromStr;
+///
+/// let uuid = UuidV7::now();
+/// let parsed = UuidV7::from_str(&uuid.to_string()).unwrap();
+/// assert_eq!(uuid, parsed);
+///
+/// assert!(UuidV7::try_from(Uuid::new_v4()).is_err());
+/// ```
+pub struct UuidV7(Uuid);
+
+impl_versioned_uuid!(UuidV7, uuid::Version::SortRand);
+
+#[cfg(feature = "std")]
+impl UuidV7 {
+    #[must_use]
+    /// Creates a new `UuidV7` with the current UTC timestamp.
+    ///
+    /// # Panics
+    ///
+    /// * If the system clock reports a time before the Unix epoch, see [`UuidV7::try_now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::UuidV7;
+    ///
+    /// let uuid = UuidV7::now();
+    /// assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+    /// ```
+    pub fn now() -> Self {
+        Self(Uuid::utc_v7())
+    }
+
+    /// Creates a new `UuidV7` with the current UTC timestamp.
+    ///
+    /// # Errors
+    ///
+    /// * If the system clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::UuidV7;
+    ///
+    /// let uuid = UuidV7::try_now().unwrap();
+    /// ```
+    pub fn try_now() -> Result<Self, Error> {
+        Uuid::try_utc_v7().map(Self)
+    }
+
+    /// Creates a new `UuidV7` with the time reported by the provided [`Clock`].
+    ///
+    /// # Errors
+    ///
+    /// * If the clock reports a time before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::{FixedClock, UuidV7};
+    ///
+    /// let uuid = UuidV7::try_with_clock(&FixedClock::new(1_700_000_000_000_000_000)).unwrap();
+    /// assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (1_700_000_000, 0));
+    /// ```
+    pub fn try_with_clock<C: Clock + ?Sized>(clock: &C) -> Result<Self, Error> {
+        Uuid::try_v7_with_clock(clock).map(Self)
+    }
+
+    #[must_use]
+    /// Creates a new `UuidV7` using the process-wide [`V7Generator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rosetta_uuid::UuidV7;
+    ///
+    /// let first = UuidV7::monotonic();
+    /// let second = UuidV7::monotonic();
+    /// assert!(first < second);
+    /// ```
+    pub fn monotonic() -> Self {
+        Self(V7Generator::global().generate())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_own_version() {
+        let v4 = Uuid::new_v4();
+        assert_eq!(UuidV4::try_from(v4).unwrap().into_uuid(), v4);
+
+        let v7 = Uuid::utc_v7();
+        assert_eq!(*UuidV7::try_from(v7).unwrap().as_uuid(), v7);
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        assert_eq!(
+            UuidV4::try_from(Uuid::utc_v7()),
+            Err(Error::UnexpectedVersion {
+                expected: uuid::Version::Random,
+                found: Some(uuid::Version::SortRand),
+            })
+        );
+        assert_eq!(
+            UuidV7::try_from(Uuid::new_v4()),
+            Err(Error::UnexpectedVersion {
+                expected: uuid::Version::SortRand,
+                found: Some(uuid::Version::Random),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_other_variants() {
+        let mut bytes: [u8; 16] = Uuid::new_v4().into();
+        // Microsoft variant: 110x.
+        bytes[8] = (bytes[8] & 0x1F) | 0xC0;
+        assert_eq!(
+            UuidV4::try_from(bytes),
+            Err(Error::UnexpectedVariant {
+                found: uuid::Variant::Microsoft
+            })
+        );
+
+        // The nil UUID is of the NCS variant.
+        assert!(UuidV4::try_from(Uuid::default()).is_err());
+        assert!(UuidV7::try_from(Uuid::default()).is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let v7 = UuidV7::now();
+        assert_eq!(UuidV7::from_str(&v7.to_string()), Ok(v7));
+        assert!(UuidV4::from_str(&v7.to_string()).is_err());
+        assert!(UuidV7::from_str("not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let v4 = UuidV4::random();
+        let inner: uuid::Uuid = v4.into();
+        assert_eq!(UuidV4::try_from(inner), Ok(v4));
+
+        let bytes: [u8; 16] = v4.into();
+        assert_eq!(UuidV4::try_from(bytes), Ok(v4));
+
+        let wrapper: Uuid = v4.into();
+        assert_eq!(wrapper, *v4);
+        assert_eq!(AsRef::<[u8; 16]>::as_ref(&v4), &bytes);
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(UuidV4::random().get_version(), Some(UuidV4::VERSION));
+        assert_eq!(UuidV7::now().get_version(), Some(UuidV7::VERSION));
+        assert_eq!(
+            UuidV7::try_now().unwrap().get_version(),
+            Some(UuidV7::VERSION)
+        );
+        assert!(UuidV7::monotonic() < UuidV7::monotonic());
+        assert!(UuidV7::try_with_clock(&crate::FixedClock::new(-1)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let v7 = UuidV7::now();
+        let json = serde_json::to_string(&v7).unwrap();
+        assert_eq!(json, format!("\"{v7}\""));
+        assert_eq!(serde_json::from_str::<UuidV7>(&json).unwrap(), v7);
+        assert!(serde_json::from_str::<UuidV4>(&json).is_err());
+    }
+}